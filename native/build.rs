@@ -23,6 +23,15 @@ fn generate_proto() -> Result<(), Box<dyn std::error::Error>> {
         .extern_path(".datafusion_common", "::datafusion_proto::protobuf")
         .extern_path(".datafusion", "::datafusion_proto::protobuf");
 
+    // Explain results are also returned as JSON, following the protobuf JSON mapping
+    let serialize = "#[derive(serde::Serialize)] #[serde(rename_all = \"camelCase\")]";
+    for message in ["ExplainResult", "ExplainPlan", "ExplainPlanNode"] {
+        cfg.message_attribute(format!(".datafusion_sharp_proto.{message}"), serialize);
+    }
+    cfg.enum_attribute(".datafusion_sharp_proto.ExplainPlan.plan", serialize);
+    // Matched without the leading dot so that the attribute is not applied to the oneof variants as well
+    cfg.field_attribute("datafusion_sharp_proto.ExplainPlan.plan", "#[serde(flatten)]");
+
     cfg.compile_protos(
         &proto_files,
         &["../proto", "../proto/vendor"],
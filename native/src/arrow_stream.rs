@@ -0,0 +1,127 @@
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+use datafusion::arrow::array::RecordBatchReader;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::error::ArrowError;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::execution::SendableRecordBatchStream;
+use futures::StreamExt;
+use log::{debug, error, trace};
+use std::sync::Arc;
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+
+use crate::{Callback, DataFrameWrapper, ErrorCode, ErrorInfo, RuntimeHandle};
+
+/// Executes the `DataFrame` and exports the result as an Arrow C stream.
+///
+/// Consumers speaking the Arrow C stream interface pull batches with `get_next` directly,
+/// without a callback round trip per batch. Each `get_next` call blocks the calling thread
+/// until the next batch is produced on the runtime.
+/// Cancelling the token makes pending and subsequent `get_next` calls fail with `EIO` and the message
+/// `Operation was cancelled`, while execution failures are reported as `EINVAL`.
+///
+/// This is an async operation. The callback is invoked on completion with a pointer to an `FFI_ArrowArrayStream`.
+/// The callback must move the stream out by copying the struct and setting `release` of the source to null,
+/// otherwise the stream is released when the callback returns.
+/// The moved stream keeps the runtime alive until the consumer calls `release`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+/// - `get_next` must not be called from within a callback invoked by this library
+/// - The exported stream holds a reference to the runtime until `release` is called, so `datafusion_runtime_destroy`
+///   fails with `RuntimeInitializationFailed` while any exported stream is alive. If `release` is called after that,
+///   the last reference is dropped on the consumer thread, which blocks while the runtime shuts down and panics if
+///   that thread is running an async context, so every stream must be released before the runtime is destroyed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_execute_arrow_stream(
+    df_ptr: *mut DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Executing Arrow C stream on DataFrame {df_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    let df_ptr_addr = df_ptr as usize;
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+
+        let stream_result = select! {
+            r = async {
+                let task_ctx = Arc::new(df.task_ctx());
+                let plan = df.create_physical_plan().await?;
+                datafusion::physical_plan::execute_stream(plan, task_ctx)
+            } => r,
+            () = cancellation_token.cancelled() => {
+                crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data);
+                return;
+            }
+        };
+
+        let stream = match stream_result {
+            Ok(stream) => stream,
+            Err(e) => {
+                error!("Failed to execute dataframe Arrow C stream: {e}");
+                let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
+        };
+
+        let reader = StreamReader {
+            runtime: Arc::clone(df_wrapper.runtime()),
+            stream,
+            cancellation_token,
+        };
+        let ffi_stream = FFI_ArrowArrayStream::new(Box::new(reader));
+
+        debug!("Executed Arrow C stream on DataFrame 0x{df_ptr_addr:x}");
+
+        crate::invoke_callback_success(ffi_stream, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Adapts a record batch stream to the blocking `RecordBatchReader` exported through the Arrow C stream interface.
+struct StreamReader {
+    runtime: RuntimeHandle,
+    stream: SendableRecordBatchStream,
+    cancellation_token: CancellationToken,
+}
+
+impl Iterator for StreamReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        trace!("Fetching next Arrow C stream batch");
+
+        // Operators may spawn tasks when polled, which requires the runtime context on the consumer thread
+        let _guard = self.runtime.enter();
+        futures::executor::block_on(async {
+            select! {
+                batch_opt = self.stream.next() => {
+                    batch_opt.map(|batch| batch.map_err(|e| ArrowError::ExternalError(Box::new(e))))
+                }
+                () = self.cancellation_token.cancelled() => {
+                    // Reported as an I/O error, so that `get_next` returns `EIO` rather than `EINVAL`
+                    let message = crate::cancellation::error().message().to_string();
+                    let source = std::io::Error::new(std::io::ErrorKind::Interrupted, message.clone());
+                    Some(Err(ArrowError::IoError(message, source)))
+                }
+            }
+        })
+    }
+}
+
+impl RecordBatchReader for StreamReader {
+    fn schema(&self) -> SchemaRef {
+        self.stream.schema()
+    }
+}
@@ -0,0 +1,441 @@
+use datafusion::catalog::{
+    CatalogProvider, MemoryCatalogProvider, MemoryCatalogProviderList, MemorySchemaProvider,
+};
+use datafusion::logical_expr::TableType;
+use log::{debug, error};
+use prost::Message;
+use std::sync::Arc;
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, SessionContextWrapper, proto};
+
+/// Creates an empty in-memory catalog in the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_create_catalog(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+
+    debug!("Creating catalog '{catalog_name}' on session {context_ptr:p}");
+
+    let result = if context.inner().catalog(&catalog_name).is_some() {
+        Err(ErrorInfo::new(
+            ErrorCode::CatalogError,
+            format!("Catalog '{catalog_name}' already exists"),
+        ))
+    } else {
+        context
+            .inner()
+            .register_catalog(&catalog_name, Arc::new(MemoryCatalogProvider::new()));
+        Ok(())
+    };
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Drops a catalog from the `SessionContext`.
+///
+/// A catalog that still contains tables is only dropped when `cascade` is true.
+/// The default catalog of the session cannot be dropped.
+///
+/// This is a synchronous operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_drop_catalog(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    cascade: bool,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+
+    debug!("Dropping catalog '{catalog_name}' (cascade={cascade}) from session {context_ptr:p}");
+
+    let options = context.inner().copied_config().options().catalog.clone();
+    let result = find_catalog(context, &catalog_name).and_then(|catalog| {
+        if catalog_name == options.default_catalog {
+            return Err(ErrorInfo::new(
+                ErrorCode::CatalogError,
+                format!("Cannot drop the default catalog '{catalog_name}'"),
+            ));
+        }
+
+        let has_tables = catalog
+            .schema_names()
+            .iter()
+            .filter_map(|s| catalog.schema(s))
+            .any(|s| !s.table_names().is_empty());
+        if has_tables && !cascade {
+            return Err(ErrorInfo::new(
+                ErrorCode::CatalogError,
+                format!("Cannot drop non-empty catalog '{catalog_name}' without cascade"),
+            ));
+        }
+
+        let state = context.inner().state();
+        let catalog_list = state
+            .catalog_list()
+            .as_any()
+            .downcast_ref::<MemoryCatalogProviderList>()
+            .ok_or_else(|| {
+                ErrorInfo::new(
+                    ErrorCode::CatalogError,
+                    "Catalog list of the session does not support dropping catalogs",
+                )
+            })?;
+        catalog_list.catalogs.remove(&catalog_name);
+
+        Ok(())
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Lists the names of all catalogs in the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked with a protobuf-encoded `NameList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_catalog_names(
+    context_ptr: *mut SessionContextWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    debug!("Listing catalogs of session {context_ptr:p}");
+
+    let mut names = context.inner().catalog_names();
+    names.sort();
+    let bytes = proto::NameList { names }.encode_to_vec();
+
+    crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates an empty in-memory schema in an existing catalog of the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_create_schema(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    schema_name_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+    let schema_name = ffi_cstr_to_string!(schema_name_ptr);
+
+    debug!("Creating schema '{catalog_name}.{schema_name}' on session {context_ptr:p}");
+
+    let result = find_catalog(context, &catalog_name).and_then(|catalog| {
+        if catalog.schema(&schema_name).is_some() {
+            return Err(ErrorInfo::new(
+                ErrorCode::CatalogError,
+                format!("Schema '{catalog_name}.{schema_name}' already exists"),
+            ));
+        }
+
+        catalog
+            .register_schema(&schema_name, Arc::new(MemorySchemaProvider::new()))
+            .map(|_| ())
+            .map_err(|e| ErrorInfo::new(ErrorCode::CatalogError, e))
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Drops a schema from a catalog of the `SessionContext`.
+///
+/// A schema that still contains tables is only dropped when `cascade` is true.
+/// The default schema of the default catalog cannot be dropped.
+///
+/// This is a synchronous operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_drop_schema(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    schema_name_ptr: *const std::ffi::c_char,
+    cascade: bool,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+    let schema_name = ffi_cstr_to_string!(schema_name_ptr);
+
+    debug!(
+        "Dropping schema '{catalog_name}.{schema_name}' (cascade={cascade}) from session {context_ptr:p}"
+    );
+
+    let options = context.inner().copied_config().options().catalog.clone();
+    let result = find_catalog(context, &catalog_name).and_then(|catalog| {
+        if catalog_name == options.default_catalog && schema_name == options.default_schema {
+            return Err(ErrorInfo::new(
+                ErrorCode::CatalogError,
+                format!("Cannot drop the default schema '{catalog_name}.{schema_name}'"),
+            ));
+        }
+
+        match catalog.deregister_schema(&schema_name, cascade) {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(ErrorInfo::new(
+                ErrorCode::CatalogError,
+                format!("Schema '{catalog_name}.{schema_name}' does not exist"),
+            )),
+            Err(e) => Err(ErrorInfo::new(ErrorCode::CatalogError, e)),
+        }
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Lists the names of all schemas in a catalog of the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked with a protobuf-encoded `NameList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_schema_names(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+
+    debug!("Listing schemas of catalog '{catalog_name}' on session {context_ptr:p}");
+
+    let result = find_catalog(context, &catalog_name).map(|catalog| {
+        let mut names = catalog.schema_names();
+        names.sort();
+        proto::NameList { names }.encode_to_vec()
+    });
+
+    match result {
+        Ok(bytes) => crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data),
+        Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+    }
+
+    ErrorCode::Ok
+}
+
+/// Lists the names of all tables in a schema of the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked with a protobuf-encoded `NameList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_table_names(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    schema_name_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+    let schema_name = ffi_cstr_to_string!(schema_name_ptr);
+
+    debug!("Listing tables of schema '{catalog_name}.{schema_name}' on session {context_ptr:p}");
+
+    let result = find_catalog(context, &catalog_name).and_then(|catalog| {
+        let schema = catalog.schema(&schema_name).ok_or_else(|| {
+            ErrorInfo::new(
+                ErrorCode::CatalogError,
+                format!("Schema '{catalog_name}.{schema_name}' does not exist"),
+            )
+        })?;
+
+        let mut names = schema.table_names();
+        names.sort();
+        Ok(proto::NameList { names }.encode_to_vec())
+    });
+
+    match result {
+        Ok(bytes) => crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data),
+        Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+    }
+
+    ErrorCode::Ok
+}
+
+/// Checks whether a table exists in the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked with a `bool` result.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_table_exist(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    debug!("Checking whether table '{table_ref}' exists on session {context_ptr:p}");
+
+    let result = context
+        .inner()
+        .table_exist(&table_ref)
+        .map_err(|e| ErrorInfo::new(ErrorCode::CatalogError, e));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Kind of a registered table, mirroring `TableType`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    Base = 0,
+    View = 1,
+    Temporary = 2,
+}
+
+impl From<TableType> for TableKind {
+    fn from(table_type: TableType) -> Self {
+        match table_type {
+            TableType::Base => TableKind::Base,
+            TableType::View => TableKind::View,
+            TableType::Temporary => TableKind::Temporary,
+        }
+    }
+}
+
+#[repr(C)]
+pub struct TableSchemaData {
+    pub schema: *const arrow_array::ffi::FFI_ArrowSchema,
+    pub table_kind: TableKind,
+}
+
+/// Returns the Arrow schema and kind of a registered table.
+///
+/// This is an async operation. The callback is invoked on completion with a `TableSchemaData` result.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_table_schema(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    debug!("Getting schema of table '{table_ref}' on session {context_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime().spawn(async move {
+        let provider_result = select! {
+            r = context.inner().table_provider(&table_ref) => {
+                r.map_err(|e| ErrorInfo::new(ErrorCode::CatalogError, e))
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        let result = provider_result.and_then(|provider| {
+            let ffi_schema = arrow_array::ffi::FFI_ArrowSchema::try_from(
+                provider.schema().as_ref(),
+            )
+            .map_err(|e| {
+                ErrorInfo::new(
+                    ErrorCode::CatalogError,
+                    format!("Failed to convert schema to FFI format: {e}"),
+                )
+            })?;
+            Ok((ffi_schema, TableKind::from(provider.table_type())))
+        });
+
+        match result {
+            Ok((ffi_schema, table_kind)) => {
+                let data = TableSchemaData {
+                    schema: &raw const ffi_schema,
+                    table_kind,
+                };
+                crate::invoke_callback_success(data, callback, user_data);
+            }
+            Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+        }
+    });
+
+    ErrorCode::Ok
+}
+
+fn find_catalog(
+    context: &SessionContextWrapper,
+    catalog_name: &str,
+) -> Result<Arc<dyn CatalogProvider>, ErrorInfo> {
+    context.inner().catalog(catalog_name).ok_or_else(|| {
+        ErrorInfo::new(
+            ErrorCode::CatalogError,
+            format!("Catalog '{catalog_name}' does not exist"),
+        )
+    })
+}
@@ -13,12 +13,35 @@ pub struct SessionContextWrapper {
 }
 
 impl SessionContextWrapper {
-    fn new(runtime: crate::RuntimeHandle) -> Self {
+    fn new(
+        runtime: crate::RuntimeHandle,
+        config: datafusion::prelude::SessionConfig,
+        runtime_env: Arc<datafusion::execution::runtime_env::RuntimeEnv>,
+    ) -> Self {
         Self {
             runtime,
-            inner: Arc::new(datafusion::prelude::SessionContext::new()),
+            inner: Arc::new(datafusion::prelude::SessionContext::new_with_config_rt(
+                config,
+                runtime_env,
+            )),
         }
     }
+
+    pub(crate) fn runtime(&self) -> &crate::RuntimeHandle {
+        &self.runtime
+    }
+
+    pub(crate) fn inner(&self) -> &datafusion::prelude::SessionContext {
+        &self.inner
+    }
+
+    /// Returns session options followed by runtime environment options.
+    fn config_entries(&self) -> Vec<datafusion::config::ConfigEntry> {
+        let state = self.inner.state();
+        let mut entries = state.config_options().entries();
+        entries.extend(state.runtime_env().config_entries());
+        entries
+    }
 }
 
 /// Creates a new `SessionContext` bound to a runtime.
@@ -27,11 +50,15 @@ impl SessionContextWrapper {
 ///
 /// # Safety
 /// - `runtime_ptr` must be a valid pointer returned by `datafusion_runtime_new`
+/// - `session_config_bytes` must be a valid `BytesData` containing a protobuf-encoded `SessionConfig`, or null
+/// - `runtime_env_config_bytes` must be a valid `BytesData` containing a protobuf-encoded `RuntimeEnvConfig`, or null
 /// - `context_out_ptr` must be a valid, aligned, non-null pointer to writable memory
 /// - Caller must call `datafusion_context_destroy` exactly once with the returned pointer
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_context_new(
     runtime_ptr: *mut crate::RuntimeHandle,
+    session_config_bytes: BytesData,
+    runtime_env_config_bytes: BytesData,
     context_out_ptr: *mut *mut SessionContextWrapper,
 ) -> ErrorCode {
     if context_out_ptr.is_null() {
@@ -40,7 +67,44 @@ pub unsafe extern "C" fn datafusion_context_new(
 
     let runtime_handle = ffi_ref!(runtime_ptr);
 
-    let context = Box::new(SessionContextWrapper::new(Arc::clone(runtime_handle)));
+    let Ok(session_config_proto) = session_config_bytes
+        .as_opt_slice()
+        .map(proto::SessionConfig::decode)
+        .transpose()
+    else {
+        error!("Failed to decode session config protobuf");
+        return ErrorCode::InvalidArgument;
+    };
+    let session_config = match mappers::from_proto_session_config(session_config_proto.as_ref()) {
+        Ok(c) => c,
+        Err(e) => {
+            error!("Failed to convert session config: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    let Ok(runtime_env_config_proto) = runtime_env_config_bytes
+        .as_opt_slice()
+        .map(proto::RuntimeEnvConfig::decode)
+        .transpose()
+    else {
+        error!("Failed to decode runtime environment config protobuf");
+        return ErrorCode::InvalidArgument;
+    };
+    let runtime_env =
+        match mappers::from_proto_runtime_env_config(runtime_env_config_proto.as_ref()) {
+            Ok(r) => r,
+            Err(e) => {
+                error!("Failed to build runtime environment: {e}");
+                return ErrorCode::InvalidArgument;
+            }
+        };
+
+    let context = Box::new(SessionContextWrapper::new(
+        Arc::clone(runtime_handle),
+        session_config,
+        runtime_env,
+    ));
     let context_ptr = Box::into_raw(context);
     unsafe {
         *context_out_ptr = context_ptr;
@@ -106,7 +170,7 @@ pub unsafe extern "C" fn datafusion_context_clone(
 ///
 /// # Safety
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
-/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
 /// - `table_path_ptr` must be a valid null-terminated UTF-8 string
 /// - `csv_options_bytes_ptr` must be a valid pointer to `BytesData` containing a Flatbuffers-encoded `CsvReadOptions` struct
 /// - `callback` must be valid to call from any thread
@@ -186,7 +250,7 @@ pub unsafe extern "C" fn datafusion_context_register_csv(
 ///
 /// # Safety
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
-/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
 /// - `table_path_ptr` must be a valid null-terminated UTF-8 string
 /// - `json_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `JsonReadOptions`, or null
 /// - `callback` must be valid to call from any thread
@@ -269,7 +333,7 @@ pub unsafe extern "C" fn datafusion_context_register_json(
 ///
 /// # Safety
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
-/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
 /// - `table_path_ptr` must be a valid null-terminated UTF-8 string
 /// - `parquet_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ParquetReadOptions`, or null
 /// - `callback` must be valid to call from any thread
@@ -350,13 +414,261 @@ pub unsafe extern "C" fn datafusion_context_register_parquet(
     ErrorCode::Ok
 }
 
+/// Reads one or more CSV files into a `DataFrame` without registering a table.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_paths_ptr` must be a valid pointer to `table_paths_len` valid null-terminated UTF-8 strings
+/// - `csv_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `CsvReadOptions`, or null
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_read_csv(
+    context_ptr: *mut SessionContextWrapper,
+    table_paths_ptr: *const *const std::ffi::c_char,
+    table_paths_len: u32,
+    csv_options_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_paths = ffi_cstr_array_to_vec!(table_paths_ptr, table_paths_len);
+
+    debug!("Reading CSV from {table_paths:?} on session {context_ptr:p}");
+
+    let csv_options_proto = match csv_options_bytes.as_opt_slice() {
+        Some(b) => match proto::CsvReadOptions::decode(b) {
+            Ok(opts) => Some(opts),
+            Err(e) => {
+                error!("Failed to decode CSV options protobuf: {e}");
+                return ErrorCode::InvalidArgument;
+            }
+        },
+        None => None,
+    };
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime.spawn(async move {
+        let schema_opt = match mappers::from_proto_schema(
+            csv_options_proto.as_ref().and_then(|o| o.schema.as_ref()),
+        ) {
+            Ok(s) => s,
+            Err(e) => {
+                let error_info = ErrorInfo::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Failed to parse CSV schema from options: {e}"),
+                );
+                crate::invoke_callback_error(&error_info, callback, user_data);
+                return;
+            }
+        };
+
+        match mappers::from_proto_csv_options(csv_options_proto.as_ref(), schema_opt.as_ref()) {
+            Ok(opts) => {
+                let result = select! {
+                    r = context.inner.read_csv(table_paths, opts) => {
+                        r.map(|df| crate::dataframe_to_ptr(&context.runtime, df))
+                         .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+                    }
+                    () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+                };
+
+                crate::invoke_callback(result, callback, user_data);
+            }
+            Err(e) => {
+                let error_info = ErrorInfo::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Failed to convert CSV options: {e}"),
+                );
+                crate::invoke_callback_error(&error_info, callback, user_data);
+            }
+        }
+    });
+
+    ErrorCode::Ok
+}
+
+/// Reads one or more JSON files into a `DataFrame` without registering a table.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_paths_ptr` must be a valid pointer to `table_paths_len` valid null-terminated UTF-8 strings
+/// - `json_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `JsonReadOptions`, or null
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_read_json(
+    context_ptr: *mut SessionContextWrapper,
+    table_paths_ptr: *const *const std::ffi::c_char,
+    table_paths_len: u32,
+    json_options_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_paths = ffi_cstr_array_to_vec!(table_paths_ptr, table_paths_len);
+
+    debug!("Reading JSON from {table_paths:?} on session {context_ptr:p}");
+
+    let json_options_proto = match json_options_bytes.as_opt_slice() {
+        Some(b) => match proto::JsonReadOptions::decode(b) {
+            Ok(opts) => Some(opts),
+            Err(e) => {
+                error!("Failed to decode JSON options protobuf: {e}");
+                return ErrorCode::InvalidArgument;
+            }
+        },
+        None => None,
+    };
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime.spawn(async move {
+        let schema_opt = match mappers::from_proto_schema(
+            json_options_proto.as_ref().and_then(|o| o.schema.as_ref()),
+        ) {
+            Ok(s) => s,
+            Err(e) => {
+                let error_info = ErrorInfo::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Failed to parse JSON schema from options: {e}"),
+                );
+                crate::invoke_callback_error(&error_info, callback, user_data);
+                return;
+            }
+        };
+
+        match mappers::from_proto_json_read_options(
+            json_options_proto.as_ref(),
+            schema_opt.as_ref(),
+        ) {
+            Ok(opts) => {
+                let result = select! {
+                    r = context.inner.read_json(table_paths, opts) => {
+                        r.map(|df| crate::dataframe_to_ptr(&context.runtime, df))
+                         .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+                    }
+                    () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+                };
+
+                crate::invoke_callback(result, callback, user_data);
+            }
+            Err(e) => {
+                let error_info = ErrorInfo::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Failed to convert JSON options: {e}"),
+                );
+                crate::invoke_callback_error(&error_info, callback, user_data);
+            }
+        }
+    });
+
+    ErrorCode::Ok
+}
+
+/// Reads one or more Parquet files into a `DataFrame` without registering a table.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_paths_ptr` must be a valid pointer to `table_paths_len` valid null-terminated UTF-8 strings
+/// - `parquet_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ParquetReadOptions`, or null
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_read_parquet(
+    context_ptr: *mut SessionContextWrapper,
+    table_paths_ptr: *const *const std::ffi::c_char,
+    table_paths_len: u32,
+    parquet_options_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_paths = ffi_cstr_array_to_vec!(table_paths_ptr, table_paths_len);
+
+    debug!("Reading Parquet from {table_paths:?} on session {context_ptr:p}");
+
+    let parquet_options_proto = match parquet_options_bytes.as_opt_slice() {
+        Some(b) => match proto::ParquetReadOptions::decode(b) {
+            Ok(opts) => Some(opts),
+            Err(e) => {
+                error!("Failed to decode Parquet options protobuf: {e}");
+                return ErrorCode::InvalidArgument;
+            }
+        },
+        None => None,
+    };
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime.spawn(async move {
+        let schema_opt = match mappers::from_proto_schema(
+            parquet_options_proto
+                .as_ref()
+                .and_then(|o| o.schema.as_ref()),
+        ) {
+            Ok(s) => s,
+            Err(e) => {
+                let error_info = ErrorInfo::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Failed to parse Parquet schema from options: {e}"),
+                );
+                crate::invoke_callback_error(&error_info, callback, user_data);
+                return;
+            }
+        };
+
+        match mappers::from_proto_parquet_read_options(
+            parquet_options_proto.as_ref(),
+            schema_opt.as_ref(),
+        ) {
+            Ok(opts) => {
+                let result = select! {
+                    r = context.inner.read_parquet(table_paths, opts) => {
+                        r.map(|df| crate::dataframe_to_ptr(&context.runtime, df))
+                         .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+                    }
+                    () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+                };
+
+                crate::invoke_callback(result, callback, user_data);
+            }
+            Err(e) => {
+                let error_info = ErrorInfo::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Failed to convert Parquet options: {e}"),
+                );
+                crate::invoke_callback_error(&error_info, callback, user_data);
+            }
+        }
+    });
+
+    ErrorCode::Ok
+}
+
 /// Registers an Arrow `RecordBatch` as a table in the `SessionContext`.
 ///
 /// This is an async operation. The callback is invoked on completion with no result data.
 ///
 /// # Safety
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
-/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
 /// - `batch_ipc_bytes` must be a valid `BytesData` containing an Arrow IPC stream with a single `RecordBatch`
 /// - `callback` must be valid to call from any thread
 #[unsafe(no_mangle)]
@@ -378,11 +690,14 @@ pub unsafe extern "C" fn datafusion_context_register_batch(
                 reader
             .next()
             .map(|batch| match batch {
-                Ok(batch) => context
-                    .inner
-                    .register_batch(&table_ref, batch)
-                    .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
-                    .map(|_| ()),
+                // `SessionContext::register_batch` treats the name as bare, so the table is
+                // registered via `register_table` to resolve qualified references
+                Ok(batch) => {
+                    datafusion::datasource::MemTable::try_new(batch.schema(), vec![vec![batch]])
+                        .and_then(|table| context.inner.register_table(&table_ref, Arc::new(table)))
+                        .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+                        .map(|_| ())
+                }
                 Err(e) => Err(ErrorInfo::new(
                     ErrorCode::InvalidArgument,
                     format!("Failed to read RecordBatch from Arrow IPC stream: {e}"),
@@ -406,6 +721,246 @@ pub unsafe extern "C" fn datafusion_context_register_batch(
     ErrorCode::Ok
 }
 
+/// Registers all `RecordBatch`es of an Arrow IPC stream as an in-memory table in the `SessionContext`.
+///
+/// Batches are distributed round-robin across `partition_count` partitions so the table can be
+/// scanned in parallel. A `partition_count` of 0 keeps all batches in a single partition.
+/// A stream that contains a schema but no batches registers an empty table.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `batches_ipc_bytes` must be a valid `BytesData` containing an Arrow IPC stream
+/// - `callback` must be valid to call from any thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_batches(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    batches_ipc_bytes: BytesData,
+    partition_count: u32,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    debug!(
+        "Registering table '{table_ref}' from Arrow IPC stream with {partition_count} partitions on session {context_ptr:p}"
+    );
+
+    let result = read_ipc_stream(batches_ipc_bytes.as_slice()).and_then(|(schema, batches)| {
+        let partitions = partition_batches(batches, partition_count as usize);
+        datafusion::datasource::MemTable::try_new(schema, partitions)
+            .and_then(|table| context.inner.register_table(&table_ref, Arc::new(table)))
+            .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+            .map(|_| ())
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Registers record batches exported through the Arrow C Data Interface as an in-memory table
+/// in the `SessionContext` without copying their buffers.
+///
+/// Each array must be a struct array whose children are the columns described by the schema.
+/// Batches are distributed across partitions the same way as in `datafusion_context_register_batches`.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `schema_ptr` must be a valid pointer to an `FFI_ArrowSchema`; it remains owned by the caller
+/// - `arrays_ptr` must be a valid pointer to `arrays_len` contiguous `FFI_ArrowArray` structs, or null if `arrays_len` is 0
+/// - Ownership of every array is transferred to the library whenever `arrays_ptr` is non-null, even on failure;
+///   the caller must not release them
+/// - `callback` must be valid to call from any thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_ffi_batches(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    schema_ptr: *const arrow_array::ffi::FFI_ArrowSchema,
+    arrays_ptr: *mut arrow_array::ffi::FFI_ArrowArray,
+    arrays_len: u32,
+    partition_count: u32,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    if arrays_ptr.is_null() && arrays_len > 0 {
+        error!("Received null pointer argument for {arrays_len} arrays");
+        return ErrorCode::InvalidArgument;
+    }
+
+    // Take ownership of all arrays before any other validation, so they are released on every failure path
+    let ffi_arrays = (0..arrays_len as usize)
+        .map(|i| unsafe { arrow_array::ffi::FFI_ArrowArray::from_raw(arrays_ptr.add(i)) })
+        .collect::<Vec<_>>();
+
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let ffi_schema = ffi_ref!(schema_ptr);
+
+    debug!(
+        "Registering table '{table_ref}' from {arrays_len} FFI arrays with {partition_count} partitions on session {context_ptr:p}"
+    );
+
+    let result = import_ffi_batches(ffi_schema, ffi_arrays).and_then(|(schema, batches)| {
+        let partitions = partition_batches(batches, partition_count as usize);
+        datafusion::datasource::MemTable::try_new(schema, partitions)
+            .and_then(|table| context.inner.register_table(&table_ref, Arc::new(table)))
+            .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+            .map(|_| ())
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Imports struct arrays exported through the Arrow C Data Interface as `RecordBatch`es.
+fn import_ffi_batches(
+    ffi_schema: &arrow_array::ffi::FFI_ArrowSchema,
+    ffi_arrays: Vec<arrow_array::ffi::FFI_ArrowArray>,
+) -> Result<
+    (
+        datafusion::arrow::datatypes::SchemaRef,
+        Vec<datafusion::arrow::array::RecordBatch>,
+    ),
+    ErrorInfo,
+> {
+    let schema = datafusion::arrow::datatypes::Schema::try_from(ffi_schema)
+        .map(Arc::new)
+        .map_err(|e| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Failed to import schema from FFI format: {e}"),
+            )
+        })?;
+
+    let batches = ffi_arrays
+        .into_iter()
+        .map(|ffi_array| {
+            crate::udf::check_ffi_children(
+                &ffi_array,
+                &datafusion::arrow::datatypes::DataType::Struct(schema.fields().clone()),
+            )?;
+            let data = unsafe { arrow_array::ffi::from_ffi(ffi_array, ffi_schema) }?;
+            // Host buffers are imported unchecked, so validate offsets and values before the batch is queried
+            data.validate_full()?;
+            if !matches!(
+                data.data_type(),
+                datafusion::arrow::datatypes::DataType::Struct(_)
+            ) {
+                return Err(datafusion::arrow::error::ArrowError::InvalidArgumentError(
+                    format!("Expected a struct array, got {}", data.data_type()),
+                ));
+            }
+            let columns = data
+                .child_data()
+                .iter()
+                .map(|child| arrow_array::make_array(child.slice(data.offset(), data.len())))
+                .collect();
+            datafusion::arrow::array::RecordBatch::try_new_with_options(
+                Arc::clone(&schema),
+                columns,
+                &datafusion::arrow::array::RecordBatchOptions::new()
+                    .with_row_count(Some(data.len())),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Failed to import RecordBatch from FFI format: {e}"),
+            )
+        })?;
+
+    Ok((schema, batches))
+}
+
+/// Reads the schema and every `RecordBatch` of an Arrow IPC stream.
+pub(crate) fn read_ipc_stream(
+    bytes: &[u8],
+) -> Result<
+    (
+        datafusion::arrow::datatypes::SchemaRef,
+        Vec<datafusion::arrow::array::RecordBatch>,
+    ),
+    ErrorInfo,
+> {
+    let reader =
+        datafusion::arrow::ipc::reader::StreamReader::try_new(bytes, None).map_err(|e| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Failed to create Arrow IPC stream reader: {e}"),
+            )
+        })?;
+
+    let schema = reader.schema();
+    let batches = reader.collect::<Result<Vec<_>, _>>().map_err(|e| {
+        ErrorInfo::new(
+            ErrorCode::InvalidArgument,
+            format!("Failed to read RecordBatch from Arrow IPC stream: {e}"),
+        )
+    })?;
+
+    Ok((schema, batches))
+}
+
+/// Distributes batches round-robin across at most `partition_count` partitions, never more than there are batches.
+fn partition_batches<T>(batches: Vec<T>, partition_count: usize) -> Vec<Vec<T>> {
+    let partition_count = partition_count.clamp(1, batches.len().max(1));
+    let mut partitions: Vec<Vec<T>> = (0..partition_count).map(|_| Vec::new()).collect();
+    for (i, batch) in batches.into_iter().enumerate() {
+        partitions[i % partition_count].push(batch);
+    }
+    partitions
+}
+
+/// Registers a `DataFrame` as a view in the `SessionContext`, so later SQL can refer to it by name.
+///
+/// The view keeps the logical plan of the `DataFrame` and is re-evaluated on every query.
+/// The `DataFrame` remains owned by the caller and can be destroyed after registration.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_view(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    df_ptr: *mut crate::DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Registering DataFrame {df_ptr:p} as view '{table_ref}' on session {context_ptr:p}");
+
+    let result = context
+        .inner
+        .register_table(&table_ref, df_wrapper.clone_inner().into_view())
+        .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+        .map(|_| ());
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
 /// Deregisters a table from the `SessionContext` by name.
 ///
 /// This is a synchronous operation.
@@ -494,7 +1049,7 @@ pub unsafe extern "C" fn datafusion_context_sql(
 
                         Ok(crate::dataframe_to_ptr(&context.runtime, df))
                     })
-                .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e))
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::SqlError, &e))
             }
             () = cancellation_token.cancelled() => Err(crate::cancellation::error())
         };
@@ -507,6 +1062,172 @@ pub unsafe extern "C" fn datafusion_context_sql(
     ErrorCode::Ok
 }
 
+/// Creates a `DataFrame` that scans a registered table, equivalent to `SessionContext::table`.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_table(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    debug!("Creating DataFrame for table '{table_ref}' on session {context_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime.spawn(async move {
+        let result = select! {
+            r = context.inner.table(&table_ref) => {
+                r.map(|df| crate::dataframe_to_ptr(&context.runtime, df))
+                 .map_err(|e| ErrorInfo::new(
+                     ErrorCode::CatalogError,
+                     format!("Failed to resolve table '{table_ref}': {e}"),
+                 ))
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Sets a configuration option of the `SessionContext`, equivalent to `SET key = value` in SQL.
+///
+/// This is an async operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `key_ptr` must be a valid null-terminated UTF-8 string with the full option name
+/// - `value_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_set_option(
+    context_ptr: *mut SessionContextWrapper,
+    key_ptr: *const std::ffi::c_char,
+    value_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let key = ffi_cstr_to_string!(key_ptr);
+    let value = ffi_cstr_to_string!(value_ptr);
+
+    debug!("Setting option '{key}' = '{value}' on session {context_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    // Executing a SET statement plan keeps runtime options and config-dependent UDFs in sync
+    let plan = datafusion::logical_expr::LogicalPlan::Statement(
+        datafusion::logical_expr::Statement::SetVariable(datafusion::logical_expr::SetVariable {
+            variable: key,
+            value,
+        }),
+    );
+
+    context.runtime.spawn(async move {
+        let result = select! {
+            r = context.inner.execute_logical_plan(plan) => {
+                r.map(|_| ())
+                 .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, e))
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Gets a configuration option of the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked with a protobuf-encoded `ConfigEntry` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `key_ptr` must be a valid null-terminated UTF-8 string with the full option name
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_get_option(
+    context_ptr: *mut SessionContextWrapper,
+    key_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let key = ffi_cstr_to_string!(key_ptr);
+
+    debug!("Getting option '{key}' on session {context_ptr:p}");
+
+    let result = context
+        .config_entries()
+        .into_iter()
+        .find(|e| e.key.eq_ignore_ascii_case(&key))
+        .map(|e| mappers::to_proto_config_entry(e).encode_to_vec())
+        .ok_or_else(|| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Unknown configuration option '{key}'"),
+            )
+        });
+
+    match result {
+        Ok(bytes) => crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data),
+        Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+    }
+
+    ErrorCode::Ok
+}
+
+/// Lists all configuration entries of the `SessionContext` with their values and descriptions.
+///
+/// This is a synchronous operation. The callback is invoked with a protobuf-encoded `ConfigEntries` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_config_entries(
+    context_ptr: *mut SessionContextWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    debug!("Listing configuration entries of session {context_ptr:p}");
+
+    let entries = proto::ConfigEntries {
+        entries: context
+            .config_entries()
+            .into_iter()
+            .map(mappers::to_proto_config_entry)
+            .collect(),
+    };
+    let bytes = entries.encode_to_vec();
+
+    crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+
+    ErrorCode::Ok
+}
+
 /// Registers a local filesystem object store for the given URL.
 ///
 /// This is a synchronous operation.
@@ -1,7 +1,8 @@
+use datafusion::physical_plan::ExecutionPlan;
 use futures::StreamExt;
 use log::{debug, error, trace};
 use prost::Message;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::select;
 use tokio_util::sync::CancellationToken;
 
@@ -10,11 +11,16 @@ use crate::{ErrorCode, ErrorInfo, mappers, proto};
 pub struct DataFrameWrapper {
     runtime: crate::RuntimeHandle,
     inner: datafusion::prelude::DataFrame,
+    executed_plan: Mutex<Option<Arc<dyn ExecutionPlan>>>,
 }
 
 impl DataFrameWrapper {
     pub fn new(runtime: crate::RuntimeHandle, inner: datafusion::prelude::DataFrame) -> Self {
-        Self { runtime, inner }
+        Self {
+            runtime,
+            inner,
+            executed_plan: Mutex::new(None),
+        }
     }
 
     pub(crate) fn runtime(&self) -> &crate::RuntimeHandle {
@@ -31,6 +37,22 @@ impl DataFrameWrapper {
 
     pub(crate) fn set_inner(&mut self, inner: datafusion::prelude::DataFrame) {
         self.inner = inner;
+        self.set_executed_plan(None);
+    }
+
+    /// Physical plan of the last completed `collect`, kept for its execution metrics.
+    pub(crate) fn executed_plan(&self) -> Option<Arc<dyn ExecutionPlan>> {
+        self.executed_plan
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    fn set_executed_plan(&self, plan: Option<Arc<dyn ExecutionPlan>>) {
+        *self
+            .executed_plan
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = plan;
     }
 }
 
@@ -141,7 +163,48 @@ pub unsafe extern "C" fn datafusion_dataframe_count(
         let result = select! {
             r = df.count() => {
                 r.map(|t| t as u64)
-                 .map_err(|e| ErrorInfo::new(ErrorCode::DataFrameError, e))
+                 .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Executes the `DataFrame` and materializes its results into an in-memory table.
+///
+/// The returned `DataFrame` scans the materialized batches instead of re-evaluating the original plan.
+///
+/// This is an async operation. The callback is invoked on completion with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_cache(
+    df_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Caching DataFrame {df_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+        let result = select! {
+            r = df.cache() => {
+                r.map(|cached| dataframe_to_ptr(df_wrapper.runtime(), cached))
+                 .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
             }
             () = cancellation_token.cancelled() => Err(crate::cancellation::error())
         };
@@ -189,7 +252,7 @@ pub unsafe extern "C" fn datafusion_dataframe_show(
                     df.show().await
                 }
             } => {
-                r.map_err(|e| ErrorInfo::new(ErrorCode::DataFrameError, e))
+                r.map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
             }
             () = cancellation_token.cancelled() => Err(crate::cancellation::error())
         };
@@ -228,7 +291,7 @@ pub unsafe extern "C" fn datafusion_dataframe_to_string(
             r = df.to_string() => {
                 match r {
                     Ok(s) => crate::invoke_callback_success(crate::BytesData::new(s.as_bytes()), callback, user_data),
-                    Err(err) => crate::invoke_callback_error(&ErrorInfo::new(ErrorCode::DataFrameError, err), callback, user_data)
+                    Err(err) => crate::invoke_callback_error(&ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &err), callback, user_data)
                 }
             },
             () = cancellation_token.cancelled() => crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data)
@@ -275,6 +338,9 @@ pub struct CollectedData {
 
 /// Materializes all records as a serialized Arrow IPC stream.
 ///
+/// The executed physical plan is kept on the `DataFrame`, and its metrics can be retrieved
+/// with `datafusion_dataframe_metrics` once the collect completes.
+///
 /// This is an async operation. The callback is invoked on completion with the serialized bytes.
 ///
 /// # Safety
@@ -307,43 +373,145 @@ pub unsafe extern "C" fn datafusion_dataframe_collect(
         };
 
         let collect_result = select! {
-            r = df.collect() => r,
+            r = async {
+                let task_ctx = Arc::new(df.task_ctx());
+                let plan = df.create_physical_plan().await?;
+                let batches = datafusion::physical_plan::collect(Arc::clone(&plan), task_ctx).await?;
+                Ok::<_, datafusion::error::DataFusionError>((plan, batches))
+            } => r,
             () = cancellation_token.cancelled() => {
                 crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data);
                 return;
             }
         };
 
-        let Ok(batches) = collect_result else {
-            error!("Failed to collect record batches");
-            let error = ErrorInfo::new(
-                ErrorCode::DataFrameError,
-                "Failed to collect record batches",
-            );
-            crate::invoke_callback_error(&error, callback, user_data);
-            return;
+        let batches = match collect_result {
+            Ok((plan, batches)) => {
+                df_wrapper.set_executed_plan(Some(plan));
+                batches
+            }
+            Err(e) => {
+                error!("Failed to collect record batches: {e}");
+                let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
         };
-        let ffi_batches = batches.iter().map(convert_batch_to_ffi).collect::<Vec<_>>();
+        invoke_callback_collected(&ffi_schema, &batches, callback, user_data);
+    });
 
-        let Ok(num_batches) = i32::try_from(ffi_batches.len()) else {
-            error!(
-                "Too many record batches ({}) to fit in i32",
-                ffi_batches.len()
-            );
+    ErrorCode::Ok
+}
+
+/// Record batches of a single output partition in FFI-compatible format.
+#[repr(C)]
+pub struct CollectedPartition {
+    pub num_batches: i32,
+    pub batches: *const arrow_array::ffi::FFI_ArrowArray, // Contiguous array of FFI_ArrowArray, one per batch
+}
+
+/// Struct to hold collected record batches grouped by output partition in FFI-compatible format.
+#[repr(C)]
+pub struct PartitionedCollectedData {
+    pub schema: *const arrow_array::ffi::FFI_ArrowSchema,
+    pub num_partitions: i32,
+    pub partitions: *const CollectedPartition, // Contiguous array of CollectedPartition, one per output partition
+}
+
+/// Materializes all records, keeping the record batches grouped by output partition.
+///
+/// The executed physical plan is kept on the `DataFrame` as in `datafusion_dataframe_collect`.
+///
+/// This is an async operation. The callback is invoked on completion with a `PartitionedCollectedData`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_collect_partitioned(
+    df_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Collecting partitions of DataFrame {df_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+
+        let ffi_schema = match convert_schema_to_ffi(&df) {
+            Ok(s) => s,
+            Err(e) => {
+                crate::invoke_callback_error(&e, callback, user_data);
+                return;
+            }
+        };
+
+        let collect_result = select! {
+            r = async {
+                let task_ctx = Arc::new(df.task_ctx());
+                let plan = df.create_physical_plan().await?;
+                let partitions = datafusion::physical_plan::collect_partitioned(Arc::clone(&plan), task_ctx).await?;
+                Ok::<_, datafusion::error::DataFusionError>((plan, partitions))
+            } => r,
+            () = cancellation_token.cancelled() => {
+                crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data);
+                return;
+            }
+        };
+
+        let partitions = match collect_result {
+            Ok((plan, partitions)) => {
+                df_wrapper.set_executed_plan(Some(plan));
+                partitions
+            }
+            Err(e) => {
+                error!("Failed to collect record batches: {e}");
+                let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
+        };
+
+        let ffi_partitions = partitions
+            .iter()
+            .map(|batches| batches.iter().map(convert_batch_to_ffi).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let collected_partitions = ffi_partitions
+            .iter()
+            .map(|ffi_batches| {
+                i32::try_from(ffi_batches.len()).map(|num_batches| CollectedPartition {
+                    num_batches,
+                    batches: ffi_batches.as_ptr(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>();
+
+        let (Ok(collected_partitions), Ok(num_partitions)) =
+            (collected_partitions, i32::try_from(ffi_partitions.len()))
+        else {
+            error!("Too many partitions or record batches to fit in i32");
             let error = ErrorInfo::new(
                 ErrorCode::DataFrameError,
-                "Too many record batches to fit in i32",
+                "Too many partitions or record batches to fit in i32",
             );
             crate::invoke_callback_error(&error, callback, user_data);
             return;
         };
 
-        debug!("Collected {num_batches} record batches");
+        debug!("Collected {num_partitions} partitions");
 
-        let result = CollectedData {
+        let result = PartitionedCollectedData {
             schema: &raw const ffi_schema,
-            num_batches,
-            batches: ffi_batches.as_ptr(),
+            num_partitions,
+            partitions: collected_partitions.as_ptr(),
         };
 
         crate::invoke_callback_success(result, callback, user_data);
@@ -355,6 +523,26 @@ pub unsafe extern "C" fn datafusion_dataframe_collect(
 pub struct DataFrameStreamWrapper {
     runtime: crate::RuntimeHandle,
     stream: datafusion::execution::SendableRecordBatchStream,
+    plan: Arc<dyn ExecutionPlan>,
+}
+
+impl DataFrameStreamWrapper {
+    pub(crate) fn new(
+        runtime: crate::RuntimeHandle,
+        stream: datafusion::execution::SendableRecordBatchStream,
+        plan: Arc<dyn ExecutionPlan>,
+    ) -> Self {
+        Self {
+            runtime,
+            stream,
+            plan,
+        }
+    }
+
+    /// Physical plan producing the stream, kept for its execution metrics.
+    pub(crate) fn plan(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.plan
+    }
 }
 
 #[repr(C)]
@@ -365,6 +553,8 @@ pub struct ExecutedStreamData {
 
 /// Executes the `DataFrame` and returns a stream of record batches as serialized Arrow IPC data.
 ///
+/// Metrics of the executed physical plan can be retrieved with `datafusion_dataframe_stream_metrics`.
+///
 /// This is an async operation. The callback is invoked on completion with a pointer to a `DataFrameStreamWrapper`.
 /// The caller can then call `datafusion_dataframe_stream_next` to retrieve each batch as bytes.
 ///
@@ -400,26 +590,33 @@ pub unsafe extern "C" fn datafusion_dataframe_execute_stream(
         };
 
         let stream_result = select! {
-            r = df.execute_stream() => r,
+            r = async {
+                let task_ctx = Arc::new(df.task_ctx());
+                let plan = df.create_physical_plan().await?;
+                let stream = datafusion::physical_plan::execute_stream(Arc::clone(&plan), task_ctx)?;
+                Ok::<_, datafusion::error::DataFusionError>((plan, stream))
+            } => r,
             () = cancellation_token.cancelled() => {
                 crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data);
                 return;
             }
         };
 
-        let Ok(stream) = stream_result else {
-            let error = ErrorInfo::new(
-                ErrorCode::DataFrameError,
-                "Failed to execute dataframe stream",
-            );
-            crate::invoke_callback_error(&error, callback, user_data);
-            return;
+        let (plan, stream) = match stream_result {
+            Ok(r) => r,
+            Err(e) => {
+                error!("Failed to execute dataframe stream: {e}");
+                let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
         };
 
-        let stream_w = Box::into_raw(Box::new(DataFrameStreamWrapper {
-            runtime: Arc::clone(df_wrapper.runtime()),
+        let stream_w = Box::into_raw(Box::new(DataFrameStreamWrapper::new(
+            Arc::clone(df_wrapper.runtime()),
             stream,
-        }));
+            plan,
+        )));
 
         let result = ExecutedStreamData {
             stream_ptr: stream_w,
@@ -434,6 +631,110 @@ pub unsafe extern "C" fn datafusion_dataframe_execute_stream(
     ErrorCode::Ok
 }
 
+/// Struct to hold one stream per output partition in FFI-compatible format.
+#[repr(C)]
+pub struct ExecutedPartitionedStreamData {
+    pub schema: *const arrow_array::ffi::FFI_ArrowSchema,
+    pub num_streams: i32,
+    pub streams: *const *mut DataFrameStreamWrapper, // Contiguous array of stream pointers, one per output partition
+}
+
+/// Executes the `DataFrame` and returns one stream of record batches per output partition.
+///
+/// The streams are independent and can be consumed concurrently from different threads,
+/// each with `datafusion_dataframe_stream_next`. All streams report the metrics of the same physical plan.
+///
+/// This is an async operation. The callback is invoked on completion with an `ExecutedPartitionedStreamData`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_stream_destroy` on every returned stream pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_execute_stream_partitioned(
+    df_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Executing partitioned streams on DataFrame {df_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    let df_ptr_addr = df_ptr as usize;
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+
+        let ffi_schema = match convert_schema_to_ffi(&df) {
+            Ok(s) => s,
+            Err(e) => {
+                crate::invoke_callback_error(&e, callback, user_data);
+                return;
+            }
+        };
+
+        let streams_result = select! {
+            r = async {
+                let task_ctx = Arc::new(df.task_ctx());
+                let plan = df.create_physical_plan().await?;
+                let streams = datafusion::physical_plan::execute_stream_partitioned(Arc::clone(&plan), task_ctx)?;
+                Ok::<_, datafusion::error::DataFusionError>((plan, streams))
+            } => r,
+            () = cancellation_token.cancelled() => {
+                crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data);
+                return;
+            }
+        };
+
+        let (plan, streams) = match streams_result {
+            Ok(r) => r,
+            Err(e) => {
+                error!("Failed to execute partitioned dataframe streams: {e}");
+                let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
+        };
+
+        let Ok(num_streams) = i32::try_from(streams.len()) else {
+            error!("Too many partitions ({}) to fit in i32", streams.len());
+            let error = ErrorInfo::new(
+                ErrorCode::DataFrameError,
+                "Too many partitions to fit in i32",
+            );
+            crate::invoke_callback_error(&error, callback, user_data);
+            return;
+        };
+
+        let stream_ptrs = streams
+            .into_iter()
+            .map(|stream| {
+                Box::into_raw(Box::new(DataFrameStreamWrapper::new(
+                    Arc::clone(df_wrapper.runtime()),
+                    stream,
+                    Arc::clone(&plan),
+                )))
+            })
+            .collect::<Vec<_>>();
+
+        let result = ExecutedPartitionedStreamData {
+            schema: &raw const ffi_schema,
+            num_streams,
+            streams: stream_ptrs.as_ptr(),
+        };
+
+        debug!("Executed {num_streams} partitioned streams on DataFrame 0x{df_ptr_addr:x}");
+
+        crate::invoke_callback_success(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
 /// Destroys a `DataFrameStreamWrapper` and frees its resources.
 ///
 /// # Safety
@@ -493,7 +794,7 @@ pub unsafe extern "C" fn datafusion_dataframe_stream_next(
                 crate::invoke_callback_success(ffi_batch, callback, user_data);
             }
             Some(Err(err)) => {
-                let error = ErrorInfo::new(ErrorCode::DataFrameError, err);
+                let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &err);
                 crate::invoke_callback_error(&error, callback, user_data);
             }
             None => crate::invoke_callback_null_result(callback, user_data),
@@ -558,7 +859,7 @@ pub unsafe extern "C" fn datafusion_dataframe_write_csv(
         let df = df_wrapper.clone_inner();
         let result = select! {
             r = df.write_csv(&path, dataframe_write_options, csv_write_options) => {
-                r.map_err(|e| ErrorInfo::new(ErrorCode::DataFrameError, e))
+                r.map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
             }
             () = cancellation_token.cancelled() => Err(crate::cancellation::error())
         };
@@ -627,7 +928,7 @@ pub unsafe extern "C" fn datafusion_dataframe_write_json(
         let df = df_wrapper.clone_inner();
         let result = select! {
             r = df.write_json(&path, dataframe_write_options, json_write_options) => {
-                r.map_err(|e| ErrorInfo::new(ErrorCode::DataFrameError, e))
+                r.map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
             }
             () = cancellation_token.cancelled() => Err(crate::cancellation::error())
         };
@@ -697,7 +998,7 @@ pub unsafe extern "C" fn datafusion_dataframe_write_parquet(
         let df = df_wrapper.clone_inner();
         let result = select! {
             r = df.write_parquet(&path, dataframe_write_options, parquet_write_options) => {
-                r.map_err(|e| ErrorInfo::new(ErrorCode::DataFrameError, e))
+                r.map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
             }
             () = cancellation_token.cancelled() => Err(crate::cancellation::error())
         };
@@ -719,8 +1020,41 @@ pub(crate) fn dataframe_to_ptr(
     ptr
 }
 
+/// Invokes the callback with record batches and their schema as a `CollectedData`.
+pub(crate) fn invoke_callback_collected(
+    ffi_schema: &arrow_array::ffi::FFI_ArrowSchema,
+    batches: &[arrow_array::RecordBatch],
+    callback: crate::Callback,
+    user_data: isize,
+) {
+    let ffi_batches = batches.iter().map(convert_batch_to_ffi).collect::<Vec<_>>();
+
+    let Ok(num_batches) = i32::try_from(ffi_batches.len()) else {
+        error!(
+            "Too many record batches ({}) to fit in i32",
+            ffi_batches.len()
+        );
+        let error = ErrorInfo::new(
+            ErrorCode::DataFrameError,
+            "Too many record batches to fit in i32",
+        );
+        crate::invoke_callback_error(&error, callback, user_data);
+        return;
+    };
+
+    debug!("Collected {num_batches} record batches");
+
+    let result = CollectedData {
+        schema: ffi_schema,
+        num_batches,
+        batches: ffi_batches.as_ptr(),
+    };
+
+    crate::invoke_callback_success(result, callback, user_data);
+}
+
 /// Helper function to convert a `DataFrame` schema to FFI format.
-fn convert_schema_to_ffi(
+pub(crate) fn convert_schema_to_ffi(
     df: &datafusion::dataframe::DataFrame,
 ) -> Result<arrow_array::ffi::FFI_ArrowSchema, ErrorInfo> {
     let schema = df.schema();
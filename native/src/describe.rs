@@ -0,0 +1,193 @@
+use arrow_array::{ArrayRef, RecordBatch, StringArray, new_null_array};
+use datafusion::arrow::compute::{cast as cast_array, concat, concat_batches};
+use datafusion::arrow::datatypes::{DataType, SchemaRef};
+use datafusion::error::Result;
+use datafusion::functions_aggregate::expr_fn::{approx_distinct, approx_percentile_cont};
+use datafusion::logical_expr::Expr;
+use datafusion::prelude::{DataFrame, cast, ident, lit};
+use log::{debug, error};
+use std::sync::Arc;
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+
+use crate::{Callback, DataFrameWrapper, ErrorCode, ErrorInfo};
+
+/// Computes summary statistics of every column of the `DataFrame`.
+///
+/// The result has a `describe` column with the statistic name and one column per input column,
+/// `Float64` for numeric columns and `Utf8` otherwise. The statistics are `count`, `null_count`,
+/// `mean`, `std`, `min`, `max` and `median`; statistics that do not apply to a `Utf8` column are the string `null`.
+///
+/// With `profile` the result additionally contains `approx_distinct` for every column and
+/// `approx_percentile_<q>` for every quantile in `quantiles_ptr`, which is null for non-numeric columns.
+/// The profile statistics are computed in a single additional pass over the data.
+/// Quantiles must be between 0 and 1, otherwise `InvalidArgument` is returned and the callback is not invoked.
+///
+/// This is an async operation. The callback is invoked on completion with a `CollectedData`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `quantiles_ptr` must point to `quantiles_len` values, or be null if `quantiles_len` is 0
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_describe(
+    df_ptr: *mut DataFrameWrapper,
+    profile: bool,
+    quantiles_ptr: *const f64,
+    quantiles_len: u32,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let quantiles = if quantiles_len == 0 {
+        Vec::new()
+    } else if quantiles_ptr.is_null() {
+        error!("Received null pointer argument for {quantiles_len} quantiles");
+        return ErrorCode::InvalidArgument;
+    } else {
+        unsafe { std::slice::from_raw_parts(quantiles_ptr, quantiles_len as usize) }.to_vec()
+    };
+    if let Some(q) = quantiles.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+        error!("Received quantile {q}, expected a value between 0 and 1");
+        return ErrorCode::InvalidArgument;
+    }
+
+    debug!("Describing DataFrame {df_ptr:p} with profile={profile}, quantiles={quantiles:?}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+        let result = select! {
+            r = describe(df, profile, &quantiles) => {
+                r.map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        let result = result.and_then(|(schema, batches)| {
+            let ffi_schema =
+                arrow_array::ffi::FFI_ArrowSchema::try_from(schema.as_ref()).map_err(|e| {
+                    ErrorInfo::new(
+                        ErrorCode::DataFrameError,
+                        format!("Failed to convert schema to FFI format: {e}"),
+                    )
+                })?;
+            Ok((ffi_schema, batches))
+        });
+
+        match result {
+            Ok((ffi_schema, batches)) => {
+                crate::invoke_callback_collected(&ffi_schema, &batches, callback, user_data);
+            }
+            Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+        }
+    });
+
+    ErrorCode::Ok
+}
+
+async fn describe(
+    df: DataFrame,
+    profile: bool,
+    quantiles: &[f64],
+) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    let described = df.clone().describe().await?;
+    let schema = Arc::clone(described.schema().inner());
+    let mut batches = described.collect().await?;
+    if profile && !df.schema().fields().is_empty() {
+        batches.push(profile_batch(df, &schema, quantiles).await?);
+    }
+    Ok((schema, batches))
+}
+
+/// Computes the approximate profile statistics in a single aggregation, laid out like the `describe` result.
+async fn profile_batch(
+    df: DataFrame,
+    describe_schema: &SchemaRef,
+    quantiles: &[f64],
+) -> Result<RecordBatch> {
+    let fields = df.schema().fields().clone();
+
+    // Aggregate output columns follow the order of the expressions
+    let mut aggr_exprs = Vec::new();
+    let mut offsets = Vec::new();
+    for field in &fields {
+        let column = ident(field.name());
+        let distinct_offset = aggr_exprs.len();
+        aggr_exprs.push(approx_distinct(distinct_input(
+            column.clone(),
+            field.data_type(),
+        )));
+        if field.data_type().is_numeric() {
+            offsets.push((distinct_offset, Some(aggr_exprs.len())));
+            for q in quantiles {
+                let input = cast(column.clone(), DataType::Float64);
+                aggr_exprs.push(approx_percentile_cont(
+                    input.sort(true, false),
+                    lit(*q),
+                    None,
+                ));
+            }
+        } else {
+            offsets.push((distinct_offset, None));
+        }
+    }
+    let aggr_exprs = aggr_exprs
+        .into_iter()
+        .enumerate()
+        .map(|(i, e)| e.alias(format!("stat_{i}")))
+        .collect();
+    let stats_df = df.aggregate(vec![], aggr_exprs)?;
+    let stats_schema = Arc::clone(stats_df.schema().inner());
+    let stats = concat_batches(&stats_schema, &stats_df.collect().await?)?;
+
+    let mut stat_names = vec!["approx_distinct".to_string()];
+    stat_names.extend(quantiles.iter().map(|q| format!("approx_percentile_{q}")));
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(StringArray::from(stat_names))];
+
+    for (i, (distinct_offset, percentile_offset)) in offsets.into_iter().enumerate() {
+        // Every cell is converted to the type `describe` uses for the column
+        let output_type = describe_schema.field(i + 1).data_type();
+        let mut cells = vec![cast_array(stats.column(distinct_offset), output_type)?];
+        for j in 0..quantiles.len() {
+            let cell = match percentile_offset {
+                Some(offset) => cast_array(stats.column(offset + j), output_type)?,
+                None => new_null_array(output_type, 1),
+            };
+            cells.push(cell);
+        }
+        columns.push(concat(
+            &cells.iter().map(AsRef::as_ref).collect::<Vec<_>>(),
+        )?);
+    }
+
+    Ok(RecordBatch::try_new(Arc::clone(describe_schema), columns)?)
+}
+
+/// Casts column types `approx_distinct` does not support to `Utf8`.
+fn distinct_input(column: Expr, data_type: &DataType) -> Expr {
+    let supported = data_type.is_integer()
+        || matches!(
+            data_type,
+            DataType::Date32
+                | DataType::Date64
+                | DataType::Time32(_)
+                | DataType::Time64(_)
+                | DataType::Timestamp(_, _)
+                | DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Utf8View
+                | DataType::Binary
+                | DataType::LargeBinary
+                | DataType::Null
+        );
+    if supported {
+        column
+    } else {
+        cast(column, DataType::Utf8)
+    }
+}
@@ -11,6 +11,9 @@ pub enum ErrorCode {
     DataFrameError = 7,
     ObjectStoreError = 8,
     Canceled = 9,
+    ResourcesExhausted = 10,
+    CatalogError = 11,
+    StreamClosed = 12,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +30,19 @@ impl ErrorInfo {
         }
     }
 
+    /// Creates an error from a `DataFusionError`, reporting memory or disk exhaustion
+    /// as `ResourcesExhausted` regardless of the given code.
+    pub fn from_datafusion(code: ErrorCode, error: &datafusion::error::DataFusionError) -> Self {
+        let code = match error.find_root() {
+            datafusion::error::DataFusionError::ResourcesExhausted(_) => {
+                ErrorCode::ResourcesExhausted
+            }
+            _ => code,
+        };
+
+        Self::new(code, error)
+    }
+
     pub fn code(&self) -> ErrorCode {
         self.code
     }
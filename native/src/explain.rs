@@ -0,0 +1,289 @@
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::common::cast::as_string_array;
+use datafusion::common::format::ExplainFormat;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{Distinct, ExplainOption, LogicalPlan, display_schema};
+use datafusion::physical_plan::{
+    DisplayFormatType, ExecutionPlan, ExecutionPlanProperties, execute_stream,
+};
+use datafusion::prelude::DataFrame;
+use futures::StreamExt;
+use log::{debug, error};
+use prost::Message;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+
+use crate::{BytesData, Callback, DataFrameWrapper, ErrorCode, ErrorInfo, proto};
+
+/// Explains the logical and physical plans of a `DataFrame`.
+///
+/// `format` is an `ExplainFormat` value:
+/// - `EXPLAIN_FORMAT_INDENT` and `EXPLAIN_FORMAT_TREE` return a protobuf-encoded `ExplainResult`
+///   with the plans rendered as text by `DataFusion`
+/// - `EXPLAIN_FORMAT_PROTOBUF` returns a protobuf-encoded `ExplainResult` with structured operator trees
+/// - `EXPLAIN_FORMAT_JSON` returns the same structured result as UTF-8 JSON using the protobuf JSON mapping
+///
+/// With `verbose` the unoptimized logical plan and operator schemas are included as well.
+/// With `analyze` the physical plan is executed and its operators report execution metrics.
+///
+/// This is an async operation. The callback is invoked on completion with the result bytes.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_explain(
+    df_ptr: *mut DataFrameWrapper,
+    verbose: bool,
+    analyze: bool,
+    format: i32,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Ok(format) = proto::ExplainFormat::try_from(format) else {
+        error!("Invalid explain format: {format}");
+        return ErrorCode::InvalidArgument;
+    };
+
+    debug!(
+        "Explaining DataFrame {df_ptr:p} with verbose={verbose}, analyze={analyze}, format={}",
+        format.as_str_name()
+    );
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+        let result = select! {
+            r = explain(df, verbose, analyze, format) => {
+                r.map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        match result {
+            Ok(bytes) => {
+                crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+            }
+            Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+        }
+    });
+
+    ErrorCode::Ok
+}
+
+async fn explain(
+    df: DataFrame,
+    verbose: bool,
+    analyze: bool,
+    format: proto::ExplainFormat,
+) -> Result<Vec<u8>> {
+    let text_format = match format {
+        proto::ExplainFormat::Indent => ExplainFormat::Indent,
+        proto::ExplainFormat::Tree => ExplainFormat::Tree,
+        proto::ExplainFormat::Protobuf => {
+            return Ok(explain_structured(df, verbose, analyze)
+                .await?
+                .encode_to_vec());
+        }
+        proto::ExplainFormat::Json => {
+            let result = explain_structured(df, verbose, analyze).await?;
+            return serde_json::to_vec(&result)
+                .map_err(|e| DataFusionError::Execution(format!("Failed to write JSON: {e}")));
+        }
+    };
+
+    let options = ExplainOption::default()
+        .with_verbose(verbose)
+        .with_analyze(analyze)
+        .with_format(text_format);
+    let batches = df.explain_with_options(options)?.collect().await?;
+
+    Ok(explain_text(&batches)?.encode_to_vec())
+}
+
+/// Converts the `plan_type` and `plan` columns returned by `EXPLAIN` into an `ExplainResult`.
+fn explain_text(batches: &[RecordBatch]) -> Result<proto::ExplainResult> {
+    let mut plans = Vec::new();
+    for batch in batches {
+        let plan_types = as_string_array(batch.column(0))?;
+        let texts = as_string_array(batch.column(1))?;
+        for (plan_type, text) in plan_types.iter().zip(texts.iter()) {
+            plans.push(proto::ExplainPlan {
+                plan_type: plan_type.unwrap_or_default().to_string(),
+                plan: Some(proto::explain_plan::Plan::Text(
+                    text.unwrap_or_default().to_string(),
+                )),
+            });
+        }
+    }
+    Ok(proto::ExplainResult { plans })
+}
+
+async fn explain_structured(
+    df: DataFrame,
+    verbose: bool,
+    analyze: bool,
+) -> Result<proto::ExplainResult> {
+    let mut plans = Vec::new();
+    let structured_plan = |plan_type: &str, root| proto::ExplainPlan {
+        plan_type: plan_type.to_string(),
+        plan: Some(proto::explain_plan::Plan::Root(root)),
+    };
+
+    if verbose {
+        plans.push(structured_plan(
+            "initial_logical_plan",
+            logical_plan_node(df.logical_plan(), verbose),
+        ));
+    }
+
+    let task_ctx = Arc::new(df.task_ctx());
+    let (state, plan) = df.into_parts();
+    // The optimized plan is both reported and planned physically, so it is optimized only once
+    let logical_plan = state.optimize(&plan)?;
+    plans.push(structured_plan(
+        "logical_plan",
+        logical_plan_node(&logical_plan, verbose),
+    ));
+
+    let physical_plan = state
+        .query_planner()
+        .create_physical_plan(&logical_plan, &state)
+        .await?;
+    if analyze {
+        // Executed only for its metrics, so batches are dropped as soon as they are produced
+        let mut stream = execute_stream(Arc::clone(&physical_plan), task_ctx)?;
+        while let Some(batch) = stream.next().await {
+            batch?;
+        }
+    }
+    plans.push(structured_plan(
+        "physical_plan",
+        physical_plan_node(&physical_plan, verbose),
+    ));
+
+    Ok(proto::ExplainResult { plans })
+}
+
+fn logical_plan_node(plan: &LogicalPlan, verbose: bool) -> proto::ExplainPlanNode {
+    let mut properties = HashMap::new();
+    if verbose {
+        properties.insert(
+            "schema".to_string(),
+            display_schema(plan.schema().as_arrow()).to_string(),
+        );
+    }
+
+    proto::ExplainPlanNode {
+        name: logical_plan_name(plan).to_string(),
+        description: plan.display().to_string(),
+        properties,
+        metrics: HashMap::new(),
+        children: plan
+            .inputs()
+            .into_iter()
+            .map(|input| logical_plan_node(input, verbose))
+            .collect(),
+    }
+}
+
+/// Returns the name of a logical operator, independent of how `DataFusion` displays its arguments.
+fn logical_plan_name(plan: &LogicalPlan) -> &str {
+    match plan {
+        LogicalPlan::Projection(_) => "Projection",
+        LogicalPlan::Filter(_) => "Filter",
+        LogicalPlan::Window(_) => "Window",
+        LogicalPlan::Aggregate(_) => "Aggregate",
+        LogicalPlan::Sort(_) => "Sort",
+        LogicalPlan::Join(_) => "Join",
+        LogicalPlan::Repartition(_) => "Repartition",
+        LogicalPlan::Union(_) => "Union",
+        LogicalPlan::TableScan(_) => "TableScan",
+        LogicalPlan::EmptyRelation(_) => "EmptyRelation",
+        LogicalPlan::Subquery(_) => "Subquery",
+        LogicalPlan::SubqueryAlias(_) => "SubqueryAlias",
+        LogicalPlan::Limit(_) => "Limit",
+        LogicalPlan::Statement(statement) => statement.name(),
+        LogicalPlan::Values(_) => "Values",
+        LogicalPlan::Explain(_) => "Explain",
+        LogicalPlan::Analyze(_) => "Analyze",
+        LogicalPlan::Extension(extension) => extension.node.name(),
+        LogicalPlan::Distinct(Distinct::All(_)) => "Distinct",
+        LogicalPlan::Distinct(Distinct::On(_)) => "DistinctOn",
+        LogicalPlan::Dml(dml) => dml.name(),
+        LogicalPlan::Ddl(ddl) => ddl.name(),
+        LogicalPlan::Copy(_) => "CopyTo",
+        LogicalPlan::DescribeTable(_) => "DescribeTable",
+        LogicalPlan::Unnest(_) => "Unnest",
+        LogicalPlan::RecursiveQuery(_) => "RecursiveQuery",
+    }
+}
+
+fn physical_plan_node(plan: &Arc<dyn ExecutionPlan>, verbose: bool) -> proto::ExplainPlanNode {
+    let mut properties = HashMap::new();
+    properties.insert(
+        "partitioning".to_string(),
+        plan.output_partitioning().to_string(),
+    );
+    if let Some(ordering) = plan.output_ordering() {
+        properties.insert("ordering".to_string(), ordering.to_string());
+    }
+    if verbose {
+        properties.insert(
+            "schema".to_string(),
+            display_schema(&plan.schema()).to_string(),
+        );
+    }
+
+    let metrics = plan
+        .metrics()
+        .map(|metrics| {
+            metrics
+                .aggregate_by_name()
+                .sorted_for_display()
+                .timestamps_removed()
+                .iter()
+                .map(|m| (m.value().name().to_string(), m.value().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    proto::ExplainPlanNode {
+        name: plan.name().to_string(),
+        description: physical_plan_description(plan.as_ref(), verbose),
+        properties,
+        metrics,
+        children: plan
+            .children()
+            .into_iter()
+            .map(|child| physical_plan_node(child, verbose))
+            .collect(),
+    }
+}
+
+/// Formats a physical operator and its arguments on a single line, without its inputs.
+pub(crate) fn physical_plan_description(plan: &dyn ExecutionPlan, verbose: bool) -> String {
+    struct Description<'a>(&'a dyn ExecutionPlan, DisplayFormatType);
+
+    impl fmt::Display for Description<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_as(self.1, f)
+        }
+    }
+
+    let format_type = if verbose {
+        DisplayFormatType::Verbose
+    } else {
+        DisplayFormatType::Default
+    };
+    Description(plan, format_type).to_string()
+}
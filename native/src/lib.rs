@@ -2,18 +2,38 @@
 mod macros;
 
 pub mod proto {
-    include!(concat!(env!("OUT_DIR"), "/datafusion_sharp_proto.rs"));
+    pub use generated::*;
+
+    // prost copies the proto comments verbatim and generates enum helpers without `#[must_use]`,
+    // so the pedantic lints are allowed for the generated code only
+    #[allow(clippy::doc_markdown, clippy::must_use_candidate)]
+    mod generated {
+        include!(concat!(env!("OUT_DIR"), "/datafusion_sharp_proto.rs"));
+    }
 }
 
+pub mod arrow_stream;
 pub mod cancellation;
+pub mod catalog;
 pub mod common;
 pub mod context;
 pub mod dataframe;
+pub mod describe;
 pub mod error;
+pub mod explain;
 pub mod logger;
 mod mappers;
 pub mod memory_store;
+pub mod metrics;
+pub mod plan;
 pub mod runtime;
+pub mod streaming_table;
+pub mod table_provider;
+pub mod transform;
+pub mod udaf;
+pub mod udf;
+pub mod udtf;
+pub mod udwf;
 
 pub use common::*;
 pub use error::*;
@@ -49,3 +49,23 @@ macro_rules! ffi_cstr_to_string {
         s
     }};
 }
+
+/// Converts a C array of C string pointers to an owned `Vec<String>`.
+/// Returns `InvalidArgument` error code if the array pointer is null or any element is null or not valid UTF-8.
+#[macro_export]
+macro_rules! ffi_cstr_array_to_vec {
+    ($ptr:expr, $len:expr) => {{
+        let ptr = $ptr;
+        let len = $len as usize;
+        if ptr.is_null() {
+            error!("Received null pointer argument for string array");
+            return $crate::ErrorCode::InvalidArgument;
+        }
+        let ptrs = unsafe { std::slice::from_raw_parts(ptr, len) };
+        let mut strings = Vec::with_capacity(ptrs.len());
+        for &p in ptrs {
+            strings.push(ffi_cstr_to_string!(p));
+        }
+        strings
+    }};
+}
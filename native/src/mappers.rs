@@ -1,11 +1,13 @@
 use anyhow::{Result, anyhow, bail};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use datafusion::arrow::datatypes::{DataType, Schema};
-use datafusion::common::ParamValues;
 use datafusion::common::metadata::{FieldMetadata, ScalarAndMetadata};
+use datafusion::common::{ParamValues, ScalarValue};
 use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
-use datafusion::logical_expr::SortExpr;
+use datafusion::logical_expr::{SortExpr, Volatility};
 use datafusion::prelude::CsvReadOptions;
 
 use crate::data_frame_param_values::Values;
@@ -149,6 +151,139 @@ pub(crate) fn from_proto_dataframe_write_options(
     Ok(dfo)
 }
 
+pub(crate) fn from_proto_session_config(
+    pbo: Option<&proto::SessionConfig>,
+) -> Result<datafusion::prelude::SessionConfig> {
+    let mut dfo = datafusion::prelude::SessionConfig::new();
+    let Some(pbo) = pbo else { return Ok(dfo) };
+
+    for (key, value) in &pbo.options {
+        dfo.options_mut()
+            .set(key, value)
+            .map_err(|e| anyhow!("Invalid session option '{key}': {e}"))?;
+    }
+
+    if let Some(target_partitions) = pbo.target_partitions {
+        if target_partitions == 0 {
+            bail!("target_partitions must be greater than zero");
+        }
+        dfo = dfo.with_target_partitions(usize::try_from(target_partitions)?);
+    }
+    if let Some(batch_size) = pbo.batch_size {
+        if batch_size == 0 {
+            bail!("batch_size must be greater than zero");
+        }
+        dfo = dfo.with_batch_size(usize::try_from(batch_size)?);
+    }
+    if let Some(time_zone) = pbo.time_zone.as_ref() {
+        dfo.options_mut().execution.time_zone = Some(time_zone.clone());
+    }
+    if let Some(information_schema) = pbo.information_schema {
+        dfo = dfo.with_information_schema(information_schema);
+    }
+    if pbo.default_catalog.is_some() || pbo.default_schema.is_some() {
+        let catalog = pbo
+            .default_catalog
+            .clone()
+            .unwrap_or_else(|| dfo.options().catalog.default_catalog.clone());
+        let schema = pbo
+            .default_schema
+            .clone()
+            .unwrap_or_else(|| dfo.options().catalog.default_schema.clone());
+        dfo = dfo.with_default_catalog_and_schema(catalog, schema);
+    }
+    if let Some(create) = pbo.create_default_catalog_and_schema {
+        dfo = dfo.with_create_default_catalog_and_schema(create);
+    }
+    if let Some(collect_statistics) = pbo.collect_statistics {
+        dfo = dfo.with_collect_statistics(collect_statistics);
+    }
+    if let Some(repartition_joins) = pbo.repartition_joins {
+        dfo = dfo.with_repartition_joins(repartition_joins);
+    }
+    if let Some(repartition_aggregations) = pbo.repartition_aggregations {
+        dfo = dfo.with_repartition_aggregations(repartition_aggregations);
+    }
+    if let Some(repartition_windows) = pbo.repartition_windows {
+        dfo = dfo.with_repartition_windows(repartition_windows);
+    }
+    if let Some(repartition_sorts) = pbo.repartition_sorts {
+        dfo = dfo.with_repartition_sorts(repartition_sorts);
+    }
+    if let Some(repartition_file_scans) = pbo.repartition_file_scans {
+        dfo = dfo.with_repartition_file_scans(repartition_file_scans);
+    }
+
+    Ok(dfo)
+}
+
+pub(crate) fn from_proto_runtime_env_config(
+    pbo: Option<&proto::RuntimeEnvConfig>,
+) -> Result<Arc<datafusion::execution::runtime_env::RuntimeEnv>> {
+    use datafusion::execution::disk_manager::{DiskManagerBuilder, DiskManagerMode};
+    use datafusion::execution::memory_pool::{
+        FairSpillPool, GreedyMemoryPool, MemoryPool, TrackConsumersPool,
+    };
+    use std::num::NonZeroUsize;
+
+    // Track the top consumers so out-of-memory errors name the operators responsible
+    const TOP_CONSUMERS: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+
+    let dfo = datafusion::execution::runtime_env::RuntimeEnvBuilder::new();
+    let Some(pbo) = pbo else {
+        return Ok(dfo.build_arc()?);
+    };
+
+    let pool_type = proto::MemoryPoolType::try_from(pbo.memory_pool_type)
+        .map_err(|_| anyhow!("invalid MemoryPoolType value: {}", pbo.memory_pool_type))?;
+    let memory_limit = pbo.memory_limit.map(usize::try_from).transpose()?;
+
+    let pool: Option<Arc<dyn MemoryPool>> =
+        match (pool_type, memory_limit) {
+            (proto::MemoryPoolType::Unbounded, None) => None,
+            (proto::MemoryPoolType::Unbounded, Some(_)) => {
+                bail!("memory_limit requires a greedy or fair spill memory pool")
+            }
+            (_, None) => bail!("memory_limit is required for a bounded memory pool"),
+            (proto::MemoryPoolType::Greedy, Some(limit)) => Some(Arc::new(
+                TrackConsumersPool::new(GreedyMemoryPool::new(limit), TOP_CONSUMERS),
+            )),
+            (proto::MemoryPoolType::FairSpill, Some(limit)) => Some(Arc::new(
+                TrackConsumersPool::new(FairSpillPool::new(limit), TOP_CONSUMERS),
+            )),
+        };
+
+    let disk_mode = proto::DiskManagerMode::try_from(pbo.disk_manager_mode)
+        .map_err(|_| anyhow!("invalid DiskManagerMode value: {}", pbo.disk_manager_mode))?;
+    let disk_mode = match disk_mode {
+        proto::DiskManagerMode::OsTmpDirectory | proto::DiskManagerMode::Disabled
+            if !pbo.temp_directories.is_empty() =>
+        {
+            bail!("temp_directories requires the directories disk manager mode")
+        }
+        proto::DiskManagerMode::OsTmpDirectory => DiskManagerMode::OsTmpDirectory,
+        proto::DiskManagerMode::Directories if pbo.temp_directories.is_empty() => {
+            bail!("temp_directories must not be empty for the directories disk manager mode")
+        }
+        proto::DiskManagerMode::Directories => {
+            DiskManagerMode::Directories(pbo.temp_directories.iter().map(PathBuf::from).collect())
+        }
+        proto::DiskManagerMode::Disabled => DiskManagerMode::Disabled,
+    };
+
+    let mut disk_manager = DiskManagerBuilder::default().with_mode(disk_mode);
+    if let Some(max_temp_directory_size) = pbo.max_temp_directory_size {
+        disk_manager = disk_manager.with_max_temp_directory_size(max_temp_directory_size);
+    }
+
+    let mut dfo = dfo.with_disk_manager_builder(disk_manager);
+    if let Some(pool) = pool {
+        dfo = dfo.with_memory_pool(pool);
+    }
+
+    Ok(dfo.build_arc()?)
+}
+
 fn first_byte(field: &'static str, bytes: &[u8]) -> Result<u8> {
     match bytes {
         [b] => Ok(*b),
@@ -270,6 +405,37 @@ pub(crate) fn from_proto_scalar_value_and_metadata(
     })
 }
 
+pub(crate) fn from_proto_arrow_type(
+    arrow_type: Option<&datafusion_proto::protobuf::ArrowType>,
+) -> Result<DataType> {
+    let arrow_type = arrow_type.ok_or_else(|| anyhow!("Missing Arrow type"))?;
+    DataType::try_from(arrow_type).map_err(|e| anyhow!("Failed to parse Arrow type: {e}"))
+}
+
+pub(crate) fn from_proto_volatility(volatility: i32) -> Result<Volatility> {
+    let volatility = match proto::Volatility::try_from(volatility)? {
+        proto::Volatility::Volatile => Volatility::Volatile,
+        proto::Volatility::Immutable => Volatility::Immutable,
+        proto::Volatility::Stable => Volatility::Stable,
+    };
+
+    Ok(volatility)
+}
+
+pub(crate) fn to_proto_scalar_value_and_metadata(
+    value: &ScalarValue,
+    metadata: Option<&FieldMetadata>,
+) -> Result<proto::ScalarValueAndMetadata> {
+    let value = value
+        .try_into()
+        .map_err(|e| anyhow!("Failed to convert scalar value: {e}"))?;
+
+    Ok(proto::ScalarValueAndMetadata {
+        value: Some(value),
+        metadata: metadata.map(FieldMetadata::to_hashmap).unwrap_or_default(),
+    })
+}
+
 pub(crate) fn from_proto_s3_object_store(
     opts: Option<&proto::S3ObjectStoreOptions>,
     url: &url::Url,
@@ -463,3 +629,11 @@ pub(crate) fn from_proto_param_values(values: &proto::DataFrameParamValues) -> R
             .map(ParamValues::Map),
     }
 }
+
+pub(crate) fn to_proto_config_entry(entry: datafusion::config::ConfigEntry) -> proto::ConfigEntry {
+    proto::ConfigEntry {
+        key: entry.key,
+        value: entry.value,
+        description: entry.description.to_owned(),
+    }
+}
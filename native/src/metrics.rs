@@ -0,0 +1,127 @@
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::metrics::{Metric, MetricValue};
+use log::{debug, error};
+use prost::Message;
+use std::sync::Arc;
+
+use crate::dataframe::DataFrameStreamWrapper;
+use crate::explain::physical_plan_description;
+use crate::{BytesData, Callback, DataFrameWrapper, ErrorCode, proto};
+
+/// Returns the execution metrics of the physical plan run by the last completed `datafusion_dataframe_collect`.
+///
+/// This is a synchronous operation.
+/// The callback is invoked with a protobuf-encoded `OperatorMetrics` tree, or null if the `DataFrame` has not been collected.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_metrics(
+    df_ptr: *mut DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Getting execution metrics of DataFrame {df_ptr:p}");
+
+    match df_wrapper.executed_plan() {
+        Some(plan) => {
+            let bytes = operator_metrics(&plan).encode_to_vec();
+            crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+        }
+        None => crate::invoke_callback_null_result(callback, user_data),
+    }
+
+    ErrorCode::Ok
+}
+
+/// Returns the execution metrics of the physical plan producing a stream.
+///
+/// Metrics reflect the batches retrieved so far and are complete once the stream is drained.
+///
+/// This is a synchronous operation.
+/// The callback is invoked with a protobuf-encoded `OperatorMetrics` tree.
+///
+/// # Safety
+/// - `stream_ptr` must be a valid pointer returned by `datafusion_dataframe_execute_stream`
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_stream_metrics(
+    stream_ptr: *mut DataFrameStreamWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let stream_wrapper = ffi_ref!(stream_ptr);
+
+    debug!("Getting execution metrics of stream {stream_ptr:p}");
+
+    let bytes = operator_metrics(stream_wrapper.plan()).encode_to_vec();
+    crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn operator_metrics(plan: &Arc<dyn ExecutionPlan>) -> proto::OperatorMetrics {
+    let (metrics, partition_metrics) = plan
+        .metrics()
+        .map(|metrics| {
+            let aggregated = metrics
+                .aggregate_by_name()
+                .sorted_for_display()
+                .iter()
+                .map(|m| to_proto_metric(m))
+                .collect();
+            let recorded = metrics.iter().map(|m| to_proto_metric(m)).collect();
+            (aggregated, recorded)
+        })
+        .unwrap_or_default();
+
+    proto::OperatorMetrics {
+        name: plan.name().to_string(),
+        description: physical_plan_description(plan.as_ref(), false),
+        metrics,
+        partition_metrics,
+        children: plan.children().into_iter().map(operator_metrics).collect(),
+    }
+}
+
+fn to_proto_metric(metric: &Metric) -> proto::Metric {
+    let value = metric.value();
+    let kind = match value {
+        MetricValue::OutputRows(_)
+        | MetricValue::SpillCount(_)
+        | MetricValue::SpilledBytes(_)
+        | MetricValue::OutputBytes(_)
+        | MetricValue::OutputBatches(_)
+        | MetricValue::SpilledRows(_)
+        | MetricValue::Count { .. } => proto::MetricKind::Count,
+        MetricValue::CurrentMemoryUsage(_) | MetricValue::Gauge { .. } => proto::MetricKind::Gauge,
+        MetricValue::ElapsedCompute(_) | MetricValue::Time { .. } => proto::MetricKind::Time,
+        MetricValue::StartTimestamp(_) | MetricValue::EndTimestamp(_) => {
+            proto::MetricKind::Timestamp
+        }
+        MetricValue::PruningMetrics { .. }
+        | MetricValue::Ratio { .. }
+        | MetricValue::Custom { .. } => proto::MetricKind::Other,
+    };
+
+    proto::Metric {
+        name: value.name().to_string(),
+        kind: kind.into(),
+        value: if kind == proto::MetricKind::Other {
+            0
+        } else {
+            value.as_usize() as u64
+        },
+        display_value: value.to_string(),
+        #[allow(clippy::cast_possible_truncation)]
+        partition: metric.partition().map(|p| p as u32),
+        labels: metric
+            .labels()
+            .iter()
+            .map(|l| (l.name().to_string(), l.value().to_string()))
+            .collect(),
+    }
+}
@@ -0,0 +1,719 @@
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
+use datafusion::catalog::{Session, TableProvider};
+use datafusion::common::tree_node::{Transformed, TreeNodeRecursion};
+use datafusion::common::{Statistics, TableReference};
+use datafusion::datasource::empty::EmptyTable;
+use datafusion::datasource::{provider_as_source, source_as_provider};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::SessionState;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::{
+    Expr, Extension, LogicalPlan, TableProviderFilterPushDown, TableType,
+};
+use datafusion::physical_expr::EquivalenceProperties;
+use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties, Partitioning,
+    PlanProperties,
+};
+use datafusion_proto::logical_plan::from_proto::parse_exprs;
+use datafusion_proto::logical_plan::to_proto::serialize_exprs;
+use datafusion_proto::logical_plan::{
+    AsLogicalPlan, DefaultLogicalExtensionCodec, LogicalExtensionCodec,
+};
+use datafusion_proto::physical_plan::{AsExecutionPlan, PhysicalExtensionCodec};
+use datafusion_proto::protobuf::{LogicalPlanNode, PhysicalPlanNode};
+use futures::TryStreamExt;
+use log::{debug, error};
+use prost::Message;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    BytesData, Callback, DataFrameStreamWrapper, DataFrameWrapper, ErrorCode, ErrorInfo,
+    ExecutedStreamData, SessionContextWrapper, proto,
+};
+
+/// Logical extension codec that encodes table scans by table reference only.
+///
+/// Listing tables and views are encoded by `datafusion-proto` itself. Any other table, such as
+/// in-memory, streaming or host tables, is resolved by name against the decoding session.
+#[derive(Debug, Default)]
+struct TableReferenceCodec {
+    providers: HashMap<TableReference, Arc<dyn TableProvider>>,
+    unresolved: Mutex<Vec<TableReference>>,
+}
+
+impl TableReferenceCodec {
+    fn with_providers(providers: HashMap<TableReference, Arc<dyn TableProvider>>) -> Self {
+        Self {
+            providers,
+            unresolved: Mutex::default(),
+        }
+    }
+
+    fn take_unresolved(&self) -> Vec<TableReference> {
+        std::mem::take(
+            &mut *self
+                .unresolved
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+}
+
+impl LogicalExtensionCodec for TableReferenceCodec {
+    fn try_decode(
+        &self,
+        _buf: &[u8],
+        _inputs: &[LogicalPlan],
+        _ctx: &TaskContext,
+    ) -> Result<Extension> {
+        Err(DataFusionError::NotImplemented(
+            "Extension logical plan nodes are not supported".to_string(),
+        ))
+    }
+
+    fn try_encode(&self, _node: &Extension, _buf: &mut Vec<u8>) -> Result<()> {
+        Err(DataFusionError::NotImplemented(
+            "Extension logical plan nodes are not supported".to_string(),
+        ))
+    }
+
+    fn try_decode_table_provider(
+        &self,
+        _buf: &[u8],
+        table_ref: &TableReference,
+        schema: SchemaRef,
+        _ctx: &TaskContext,
+    ) -> Result<Arc<dyn TableProvider>> {
+        if let Some(provider) = self.providers.get(table_ref) {
+            return Ok(Arc::clone(provider));
+        }
+
+        // Providers are looked up asynchronously, so record the table and decode it again once resolved
+        self.unresolved
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(table_ref.clone());
+        Ok(Arc::new(EmptyTable::new(schema)))
+    }
+
+    fn try_encode_table_provider(
+        &self,
+        _table_ref: &TableReference,
+        _node: Arc<dyn TableProvider>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Decodes a logical plan, resolving tables encoded by reference against the session.
+async fn decode_logical_plan(
+    context: &datafusion::prelude::SessionContext,
+    plan_node: &LogicalPlanNode,
+) -> Result<LogicalPlan> {
+    let task_ctx = context.task_ctx();
+
+    let codec = TableReferenceCodec::default();
+    let plan = plan_node.try_into_logical_plan(&task_ctx, &codec)?;
+    let unresolved = codec.take_unresolved();
+    if unresolved.is_empty() {
+        return Ok(plan);
+    }
+
+    let mut providers = HashMap::with_capacity(unresolved.len());
+    for table_ref in unresolved {
+        let provider = context.table_provider(table_ref.clone()).await?;
+        providers.insert(table_ref, provider);
+    }
+
+    let codec = TableReferenceCodec::with_providers(providers);
+    plan_node.try_into_logical_plan(&task_ctx, &codec)
+}
+
+/// Serializes the logical plan of a `DataFrame` as a protobuf-encoded `datafusion.LogicalPlanNode`.
+///
+/// Tables other than listing tables and views are encoded by name and must be registered
+/// under the same name in the session that imports the plan.
+///
+/// This is a synchronous operation. The callback is invoked on completion with the serialized bytes.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_logical_plan_to_bytes(
+    df_ptr: *mut DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Serializing logical plan of DataFrame {df_ptr:p}");
+
+    let codec = TableReferenceCodec::default();
+    match LogicalPlanNode::try_from_logical_plan(df_wrapper.inner().logical_plan(), &codec) {
+        Ok(plan_node) => {
+            let bytes = plan_node.encode_to_vec();
+            crate::invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+        }
+        Err(e) => {
+            error!("Failed to serialize logical plan: {e}");
+            let error = ErrorInfo::new(
+                ErrorCode::DataFrameError,
+                format!("Failed to serialize logical plan: {e}"),
+            );
+            crate::invoke_callback_error(&error, callback, user_data);
+        }
+    }
+
+    ErrorCode::Ok
+}
+
+/// Creates a `DataFrame` from a protobuf-encoded `datafusion.LogicalPlanNode`.
+///
+/// Tables and functions referenced by the plan are resolved against the `SessionContext`.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `plan_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.LogicalPlanNode`
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_dataframe_from_logical_plan(
+    context_ptr: *mut SessionContextWrapper,
+    plan_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let plan_node = match LogicalPlanNode::decode(plan_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode logical plan protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Creating DataFrame from logical plan on session {context_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime().spawn(async move {
+        let result = select! {
+            r = decode_logical_plan(context.inner(), &plan_node) => {
+                r.map(|plan| {
+                    let df = datafusion::prelude::DataFrame::new(context.inner().state(), plan);
+                    crate::dataframe_to_ptr(context.runtime(), df)
+                })
+                .map_err(|e| {
+                    ErrorInfo::new(
+                        ErrorCode::DataFrameError,
+                        format!("Failed to deserialize logical plan: {e}"),
+                    )
+                })
+            }
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Table provider that wraps the scans of a registered table in a `TableReferenceExec`.
+#[derive(Debug)]
+struct TableReferenceProvider {
+    table_ref: TableReference,
+    inner: Arc<dyn TableProvider>,
+}
+
+#[async_trait]
+impl TableProvider for TableReferenceProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    fn table_type(&self) -> TableType {
+        self.inner.table_type()
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let inner = self.inner.scan(state, projection, filters, limit).await?;
+        Ok(Arc::new(TableReferenceExec {
+            table_ref: self.table_ref.clone(),
+            projection: projection.cloned(),
+            filters: filters.to_vec(),
+            limit,
+            properties: Arc::clone(inner.properties()),
+            source: TableReferenceSource::Planned(inner),
+        }))
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.inner.supports_filters_pushdown(filters)
+    }
+}
+
+/// Scan of a registered table that is serialized by table reference and repeated by the executing session.
+#[derive(Debug)]
+struct TableReferenceExec {
+    table_ref: TableReference,
+    projection: Option<Vec<usize>>,
+    filters: Vec<Expr>,
+    limit: Option<usize>,
+    properties: Arc<PlanProperties>,
+    source: TableReferenceSource,
+}
+
+#[derive(Debug)]
+enum TableReferenceSource {
+    /// Scan planned by the session that created the plan.
+    Planned(Arc<dyn ExecutionPlan>),
+    /// Session that resolves and scans the table when a partition is executed.
+    Session(Arc<SessionState>),
+}
+
+impl DisplayAs for TableReferenceExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TableReferenceExec: table={}", self.table_ref)?;
+        if let Some(projection) = &self.projection {
+            write!(f, ", projection={projection:?}")?;
+        }
+        if !self.filters.is_empty() {
+            let filters = self.filters.iter().map(ToString::to_string);
+            write!(f, ", filters=[{}]", filters.collect::<Vec<_>>().join(", "))?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, ", limit={limit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ExecutionPlan for TableReferenceExec {
+    fn name(&self) -> &'static str {
+        "TableReferenceExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &Arc<PlanProperties> {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let session = match &self.source {
+            TableReferenceSource::Planned(inner) => return inner.execute(partition, context),
+            TableReferenceSource::Session(session) => Arc::clone(session),
+        };
+
+        let table_ref = self.table_ref.clone();
+        let projection = self.projection.clone();
+        let filters = self.filters.clone();
+        let limit = self.limit;
+        let partition_count = self.properties.output_partitioning().partition_count();
+
+        // Table providers scan asynchronously, so the scan is planned when the stream is first polled
+        let stream = futures::stream::once(async move {
+            let provider = session
+                .schema_for_ref(table_ref.clone())?
+                .table(table_ref.table())
+                .await?
+                .ok_or_else(|| {
+                    DataFusionError::Plan(format!("Table '{table_ref}' is not registered"))
+                })?;
+            let plan = provider
+                .scan(session.as_ref(), projection.as_ref(), &filters, limit)
+                .await?;
+            let scanned_partition_count = plan.output_partitioning().partition_count();
+            if scanned_partition_count != partition_count {
+                return Err(DataFusionError::Execution(format!(
+                    "Table '{table_ref}' is scanned with {scanned_partition_count} partitions, but the plan was created with {partition_count}"
+                )));
+            }
+            plan.execute(partition, context)
+        })
+        .try_flatten();
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn partition_statistics(&self, partition: Option<usize>) -> Result<Statistics> {
+        match &self.source {
+            TableReferenceSource::Planned(inner) => inner.partition_statistics(partition),
+            TableReferenceSource::Session(_) => Ok(Statistics::new_unknown(&self.schema())),
+        }
+    }
+}
+
+/// Physical extension codec that encodes scans of registered tables by table reference.
+///
+/// Decoded scans are resolved by name against the session of the codec when they are executed.
+#[derive(Debug, Default)]
+struct PhysicalTableReferenceCodec {
+    session: Option<Arc<SessionState>>,
+}
+
+impl PhysicalExtensionCodec for PhysicalTableReferenceCodec {
+    fn try_decode(
+        &self,
+        buf: &[u8],
+        _inputs: &[Arc<dyn ExecutionPlan>],
+        ctx: &TaskContext,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let Some(session) = &self.session else {
+            return Err(DataFusionError::Internal(
+                "Table references can only be decoded against a session".to_string(),
+            ));
+        };
+
+        let node = proto::TableScanReference::decode(buf)
+            .map_err(|e| DataFusionError::Plan(format!("Invalid table scan reference: {e}")))?;
+        let table_ref = TableReference::try_from(node.table.unwrap_or_default())
+            .map_err(|e| DataFusionError::Plan(format!("Invalid table reference: {e}")))?;
+        if !session
+            .schema_for_ref(table_ref.clone())
+            .is_ok_and(|schema| schema.table_exist(table_ref.table()))
+        {
+            return Err(DataFusionError::Plan(format!(
+                "Table '{table_ref}' referenced by the physical plan is not registered in the session"
+            )));
+        }
+
+        let schema = Arc::new(Schema::try_from(&node.schema.unwrap_or_default())?);
+        let filters = parse_exprs(&node.filters, ctx, &DefaultLogicalExtensionCodec {})?;
+        let boundedness = if node.unbounded {
+            Boundedness::Unbounded {
+                requires_infinite_memory: false,
+            }
+        } else {
+            Boundedness::Bounded
+        };
+        let properties = PlanProperties::new(
+            EquivalenceProperties::new(schema),
+            Partitioning::UnknownPartitioning(node.partition_count as usize),
+            EmissionType::Incremental,
+            boundedness,
+        );
+
+        Ok(Arc::new(TableReferenceExec {
+            table_ref,
+            projection: node
+                .projection
+                .map(|p| p.columns.into_iter().map(|c| c as usize).collect()),
+            filters,
+            limit: node.limit.map(|l| usize::try_from(l).unwrap_or(usize::MAX)),
+            properties: Arc::new(properties),
+            source: TableReferenceSource::Session(Arc::clone(session)),
+        }))
+    }
+
+    fn try_encode(&self, node: Arc<dyn ExecutionPlan>, buf: &mut Vec<u8>) -> Result<()> {
+        let Some(exec) = node.as_any().downcast_ref::<TableReferenceExec>() else {
+            return Err(DataFusionError::NotImplemented(format!(
+                "Physical plan node {} cannot be serialized",
+                node.name()
+            )));
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let node = proto::TableScanReference {
+            table: Some(exec.table_ref.clone().into()),
+            schema: Some((&*exec.schema()).try_into()?),
+            projection: exec
+                .projection
+                .as_ref()
+                .map(|p| proto::TableScanProjection {
+                    columns: p.iter().map(|&c| c as u32).collect(),
+                }),
+            filters: serialize_exprs(&exec.filters, &DefaultLogicalExtensionCodec {})?,
+            limit: exec.limit.map(|l| l as u64),
+            partition_count: exec.properties.output_partitioning().partition_count() as u32,
+            unbounded: exec.properties.boundedness.is_unbounded(),
+        };
+        node.encode(buf)
+            .map_err(|e| DataFusionError::Internal(format!("Failed to encode table scan: {e}")))
+    }
+}
+
+/// Creates the physical plan of a `DataFrame` with the scans of registered tables wrapped in a `TableReferenceExec`.
+async fn create_physical_plan_by_reference(
+    df: datafusion::prelude::DataFrame,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    let (state, plan) = df.into_parts();
+    // Optimized first so that views are inlined and filters are pushed into the scans
+    let plan = state.optimize(&plan)?;
+
+    let mut scans = Vec::new();
+    plan.apply_with_subqueries(|node| {
+        if let LogicalPlan::TableScan(scan) = node {
+            scans.push((scan.table_name.clone(), source_as_provider(&scan.source)?));
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+
+    // Only tables the session can resolve by name are encoded by reference
+    let mut registered = HashSet::new();
+    for (table_ref, provider) in scans {
+        let Ok(schema) = state.schema_for_ref(table_ref.clone()) else {
+            continue;
+        };
+        if let Some(table) = schema.table(table_ref.table()).await?
+            && std::ptr::addr_eq(Arc::as_ptr(&table), Arc::as_ptr(&provider))
+        {
+            registered.insert(table_ref);
+        }
+    }
+
+    let plan = plan
+        .transform_up_with_subqueries(|node| match node {
+            LogicalPlan::TableScan(mut scan) if registered.contains(&scan.table_name) => {
+                let provider = TableReferenceProvider {
+                    table_ref: scan.table_name.clone(),
+                    inner: source_as_provider(&scan.source)?,
+                };
+                scan.source = provider_as_source(Arc::new(provider));
+                Ok(Transformed::yes(LogicalPlan::TableScan(scan)))
+            }
+            _ => Ok(Transformed::no(node)),
+        })?
+        .data;
+
+    state
+        .query_planner()
+        .create_physical_plan(&plan, &state)
+        .await
+}
+
+/// Serialized physical plan together with its number of output partitions.
+#[repr(C)]
+pub struct PhysicalPlanData {
+    pub plan: BytesData,
+    pub partition_count: u32,
+}
+
+/// Creates the physical plan of a `DataFrame` and serializes it as a protobuf-encoded `datafusion.PhysicalPlanNode`.
+///
+/// Scans of registered tables are encoded by name and repeated by the session executing the plan, which must
+/// have the same tables registered under the same names. Other sources, such as the files of `read_csv` or
+/// cached `DataFrame`s, are serialized with their paths or data; sources that `datafusion-proto` cannot
+/// serialize fail with an error.
+///
+/// This is an async operation. The callback is invoked on completion with a `PhysicalPlanData`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_physical_plan_to_bytes(
+    df_ptr: *mut DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Serializing physical plan of DataFrame {df_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    df_wrapper.runtime().spawn(async move {
+        let df = df_wrapper.clone_inner();
+
+        let plan_result = select! {
+            r = create_physical_plan_by_reference(df) => r,
+            () = cancellation_token.cancelled() => {
+                crate::invoke_callback_error(&crate::cancellation::error(), callback, user_data);
+                return;
+            }
+        };
+
+        let result = plan_result
+            .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e))
+            .and_then(|plan| {
+                let partition_count = plan.output_partitioning().partition_count();
+                PhysicalPlanNode::try_from_physical_plan(
+                    plan,
+                    &PhysicalTableReferenceCodec::default(),
+                )
+                .map(|node| (node.encode_to_vec(), partition_count))
+                .map_err(|e| {
+                    ErrorInfo::new(
+                        ErrorCode::DataFrameError,
+                        format!("Failed to serialize physical plan: {e}"),
+                    )
+                })
+            });
+
+        match result {
+            Ok((bytes, partition_count)) => {
+                #[allow(clippy::cast_possible_truncation)]
+                let data = PhysicalPlanData {
+                    plan: BytesData::new(&bytes),
+                    partition_count: partition_count as u32,
+                };
+                crate::invoke_callback_success(data, callback, user_data);
+            }
+            Err(e) => {
+                error!("Failed to serialize physical plan: {}", e.message());
+                crate::invoke_callback_error(&e, callback, user_data);
+            }
+        }
+    });
+
+    ErrorCode::Ok
+}
+
+/// Deserializes a protobuf-encoded `datafusion.PhysicalPlanNode` and executes a single output partition of it.
+///
+/// Scans encoded by table reference are resolved by name against the `SessionContext` and fail to deserialize
+/// when the table is not registered. Tables must be scanned with the same number of partitions as when the plan
+/// was created, so sessions should share the configuration that determines scan partitioning.
+///
+/// This is an async operation. The callback is invoked on completion with an `ExecutedStreamData`.
+/// The caller can then call `datafusion_dataframe_stream_next` to retrieve each batch.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `plan_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.PhysicalPlanNode`
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_stream_destroy` on the returned stream pointer
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_execute_physical_plan_partition(
+    context_ptr: *mut SessionContextWrapper,
+    plan_bytes: BytesData,
+    partition: u32,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let plan_node = match PhysicalPlanNode::decode(plan_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode physical plan protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Executing partition {partition} of physical plan on session {context_ptr:p}");
+
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    context.runtime().spawn(async move {
+        let result = select! {
+            r = async {
+                let task_ctx = context.inner().task_ctx();
+                let codec = PhysicalTableReferenceCodec {
+                    session: Some(Arc::new(context.inner().state())),
+                };
+                let plan = plan_node
+                    .try_into_physical_plan(&task_ctx, &codec)
+                    .map_err(|e| {
+                        ErrorInfo::new(
+                            ErrorCode::DataFrameError,
+                            format!("Failed to deserialize physical plan: {e}"),
+                        )
+                    })?;
+
+                let partition_count = plan.output_partitioning().partition_count();
+                if partition as usize >= partition_count {
+                    return Err(ErrorInfo::new(
+                        ErrorCode::InvalidArgument,
+                        format!("Partition {partition} is out of range for a plan with {partition_count} partitions"),
+                    ));
+                }
+
+                let ffi_schema = arrow_array::ffi::FFI_ArrowSchema::try_from(plan.schema().as_ref())
+                    .map_err(|e| {
+                        ErrorInfo::new(
+                            ErrorCode::DataFrameError,
+                            format!("Failed to convert schema to FFI format: {e}"),
+                        )
+                    })?;
+
+                let stream = plan.execute(partition as usize, task_ctx).map_err(|e| {
+                    error!("Failed to execute physical plan partition: {e}");
+                    ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e)
+                })?;
+
+                Ok((plan, stream, ffi_schema))
+            } => r,
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        match result {
+            Ok((plan, stream, ffi_schema)) => {
+                let stream_w = Box::into_raw(Box::new(DataFrameStreamWrapper::new(
+                    Arc::clone(context.runtime()),
+                    stream,
+                    plan,
+                )));
+                debug!("Executed stream {stream_w:p} for partition {partition} of physical plan");
+
+                let result = ExecutedStreamData {
+                    stream_ptr: stream_w,
+                    schema: &raw const ffi_schema,
+                };
+                crate::invoke_callback_success(result, callback, user_data);
+            }
+            Err(e) => crate::invoke_callback_error(&e, callback, user_data),
+        }
+    });
+
+    ErrorCode::Ok
+}
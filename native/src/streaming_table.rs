@@ -0,0 +1,383 @@
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::catalog::streaming::StreamingTable;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+use futures::StreamExt;
+use log::{debug, error};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::select;
+use tokio::sync::{Notify, mpsc};
+use tokio_util::sync::CancellationToken;
+
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// Handle used by the host to push record batches into a streaming table.
+pub struct StreamingTableWrapper {
+    runtime: crate::RuntimeHandle,
+    schema: SchemaRef,
+    channel: Arc<PushChannel>,
+}
+
+impl Drop for StreamingTableWrapper {
+    fn drop(&mut self) {
+        // The partition stream keeps the channel alive, so running and later scans must be ended explicitly
+        self.channel.finish();
+    }
+}
+
+/// Hands the batches pushed by the host to the running scan of a streaming table.
+///
+/// Batches pushed while no scan is running, and batches left unread by a scan that ended early,
+/// are kept for the next scan, so every batch is consumed by exactly one scan.
+#[derive(Debug)]
+struct PushChannel {
+    capacity: usize,
+    state: Mutex<PushState>,
+    scan_started: Notify,
+}
+
+#[derive(Debug, Default)]
+struct PushState {
+    scan: Option<mpsc::Sender<RecordBatch>>,
+    pending: VecDeque<RecordBatch>,
+    finished: bool,
+}
+
+impl PushChannel {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::default(),
+            scan_started: Notify::new(),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, PushState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Starts a scan that receives the pending batches followed by the batches pushed while it runs.
+    fn subscribe(self: &Arc<Self>) -> Result<ScanReceiver> {
+        let mut state = self.state();
+        if state.scan.as_ref().is_some_and(|scan| !scan.is_closed()) {
+            return Err(DataFusionError::Execution(
+                "Streaming table is already being scanned; it cannot be scanned concurrently, \
+                 including more than once by the same query"
+                    .to_string(),
+            ));
+        }
+
+        // Pending batches never exceed the capacity, so they all fit into the new buffer
+        let (sender, receiver) = mpsc::channel(self.capacity);
+        for batch in state.pending.drain(..) {
+            let _ = sender.try_send(batch);
+        }
+        // Once finished the scan ends after the pending batches
+        state.scan = (!state.finished).then_some(sender);
+        drop(state);
+        self.scan_started.notify_waiters();
+
+        Ok(ScanReceiver {
+            channel: Arc::clone(self),
+            receiver,
+        })
+    }
+
+    /// Delivers a batch to the running scan, or keeps it for the next scan when none is running.
+    async fn send(&self, mut batch: RecordBatch) -> Result<(), ErrorInfo> {
+        loop {
+            let scan_started = self.scan_started.notified();
+            tokio::pin!(scan_started);
+            // Register for wakeups before inspecting the state so a concurrent scan start is not missed
+            scan_started.as_mut().enable();
+
+            let scan = {
+                let mut state = self.state();
+                if state.finished {
+                    return Err(ErrorInfo::new(
+                        ErrorCode::StreamClosed,
+                        "Streaming table is finished",
+                    ));
+                }
+
+                match &state.scan {
+                    Some(scan) if !scan.is_closed() => Some(scan.clone()),
+                    _ if state.pending.len() < self.capacity => {
+                        state.pending.push_back(batch);
+                        return Ok(());
+                    }
+                    _ => None,
+                }
+            };
+
+            match scan {
+                Some(scan) => match scan.send(batch).await {
+                    Ok(()) => return Ok(()),
+                    // The scan ended while waiting for buffer space
+                    Err(mpsc::error::SendError(unsent)) => batch = unsent,
+                },
+                None => scan_started.await,
+            }
+        }
+    }
+
+    /// Ends the running scan once the batches buffered for it are consumed.
+    fn finish(&self) {
+        let mut state = self.state();
+        state.finished = true;
+        state.scan = None;
+        drop(state);
+        self.scan_started.notify_waiters();
+    }
+}
+
+/// Receiving end of a scan that returns its unread batches to the channel when dropped.
+struct ScanReceiver {
+    channel: Arc<PushChannel>,
+    receiver: mpsc::Receiver<RecordBatch>,
+}
+
+impl ScanReceiver {
+    fn into_stream(self) -> futures::stream::BoxStream<'static, RecordBatch> {
+        futures::stream::unfold(self, |mut scan| async move {
+            scan.receiver.recv().await.map(|batch| (batch, scan))
+        })
+        .boxed()
+    }
+}
+
+impl Drop for ScanReceiver {
+    fn drop(&mut self) {
+        // Locked first so that pushes rejected by the closed buffer are queued after the unread batches
+        let mut state = self.channel.state();
+        self.receiver.close();
+        let mut unread = VecDeque::new();
+        while let Ok(batch) = self.receiver.try_recv() {
+            unread.push_back(batch);
+        }
+        unread.append(&mut state.pending);
+        state.pending = unread;
+    }
+}
+
+/// Partition of a streaming table that yields batches received from the host.
+///
+/// Scans consume the pushed batches one after another; a scan started while another is running fails.
+#[derive(Debug)]
+struct ChannelPartitionStream {
+    schema: SchemaRef,
+    channel: Arc<PushChannel>,
+}
+
+impl PartitionStream for ChannelPartitionStream {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let stream: futures::stream::BoxStream<'static, Result<RecordBatch>> =
+            match self.channel.subscribe() {
+                Ok(scan) => scan.into_stream().map(Ok).boxed(),
+                Err(e) => futures::stream::once(async { Err(e) }).boxed(),
+            };
+
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            stream,
+        ))
+    }
+}
+
+/// Registers a table whose record batches are pushed by the host over time.
+///
+/// The callback is invoked with a `StreamingTableWrapper` pointer used to push batches with
+/// `datafusion_streaming_table_push_batch` and to end the stream with `datafusion_streaming_table_finish`.
+/// When `unbounded` is true the table is planned as an infinite source.
+///
+/// The table can be scanned by any number of queries, one at a time, and every pushed batch is consumed
+/// by exactly one scan. Up to `capacity` batches are buffered before pushes wait for the running scan to
+/// consume them; a `capacity` of 0 is treated as 1. Batches pushed while no scan is running, and batches left
+/// unread by a scan that ended early, are kept for the next scan. A scan started while another one is running,
+/// including a second scan of the table within the same query such as a self-join, fails.
+///
+/// This is a synchronous operation.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `schema_ptr` must be a valid pointer to an `FFI_ArrowSchema`; it remains owned by the caller
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_streaming_table_destroy` on the returned pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_streaming_table(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    schema_ptr: *const arrow_array::ffi::FFI_ArrowSchema,
+    capacity: u32,
+    unbounded: bool,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let ffi_schema = ffi_ref!(schema_ptr);
+
+    debug!(
+        "Registering streaming table '{table_ref}' with capacity {capacity} on session {context_ptr:p}"
+    );
+
+    let schema = match datafusion::arrow::datatypes::Schema::try_from(ffi_schema) {
+        Ok(schema) => Arc::new(schema),
+        Err(e) => {
+            let error = ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Failed to import schema from FFI format: {e}"),
+            );
+            crate::invoke_callback_error(&error, callback, user_data);
+            return ErrorCode::Ok;
+        }
+    };
+
+    let channel = Arc::new(PushChannel::new(capacity.max(1) as usize));
+    let partition = ChannelPartitionStream {
+        schema: Arc::clone(&schema),
+        channel: Arc::clone(&channel),
+    };
+
+    let result = StreamingTable::try_new(Arc::clone(&schema), vec![Arc::new(partition)])
+        .map(|table| table.with_infinite_table(unbounded))
+        .and_then(|table| context.inner().register_table(&table_ref, Arc::new(table)))
+        .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+        .map(|_| {
+            let table_w = Box::into_raw(Box::new(StreamingTableWrapper {
+                runtime: Arc::clone(context.runtime()),
+                schema,
+                channel,
+            }));
+            debug!("Created streaming table {table_w:p} for '{table_ref}'");
+            table_w
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Destroys a `StreamingTableWrapper`, finishing the stream if it has not been finished yet.
+///
+/// # Safety
+/// - `table_ptr` must be a valid pointer returned by `datafusion_context_register_streaming_table`, or null
+/// - Caller must not use `table_ptr` after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_streaming_table_destroy(
+    table_ptr: *mut StreamingTableWrapper,
+) -> ErrorCode {
+    debug!("Destroying streaming table {table_ptr:p}");
+
+    if !table_ptr.is_null() {
+        unsafe { drop(Box::from_raw(table_ptr)) };
+    }
+
+    ErrorCode::Ok
+}
+
+/// Pushes all record batches of an Arrow IPC stream into a streaming table.
+///
+/// This is an async operation. The callback is invoked with no result data once every batch
+/// has been accepted into the buffer, so it is delayed while the query side falls behind or,
+/// when no scan is running and `capacity` batches are already kept, until a scan starts.
+///
+/// # Safety
+/// - `table_ptr` must be a valid pointer returned by `datafusion_context_register_streaming_table`
+/// - `batches_ipc_bytes` must be a valid `BytesData` containing an Arrow IPC stream
+/// - `callback` must be valid to call from any thread
+/// - `cancellation_token_out_ptr` must be a valid pointer to writable memory or null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_streaming_table_push_batch(
+    table_ptr: *mut StreamingTableWrapper,
+    batches_ipc_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+    cancellation_token_out_ptr: *mut *mut CancellationToken,
+) -> ErrorCode {
+    let table = ffi_ref!(table_ptr);
+
+    debug!("Pushing batches into streaming table {table_ptr:p}");
+
+    // Hand out the token before decoding, so the host sees a started operation even when decoding fails
+    let cancellation_token = CancellationToken::new();
+    crate::cancellation::into_raw_ptr(&cancellation_token, cancellation_token_out_ptr);
+
+    let batches = match crate::context::read_ipc_stream(batches_ipc_bytes.as_slice()).and_then(
+        |(_, batches)| {
+            batches
+                .into_iter()
+                .map(|batch| batch.with_schema(Arc::clone(&table.schema)))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    ErrorInfo::new(
+                        ErrorCode::InvalidArgument,
+                        format!("RecordBatch does not match streaming table schema: {e}"),
+                    )
+                })
+        },
+    ) {
+        Ok(batches) => batches,
+        Err(e) => {
+            crate::invoke_callback_error(&e, callback, user_data);
+            return ErrorCode::Ok;
+        }
+    };
+
+    let channel = Arc::clone(&table.channel);
+    table.runtime.spawn(async move {
+        let send_all = async {
+            for batch in batches {
+                channel.send(batch).await?;
+            }
+            Ok(())
+        };
+
+        let result = select! {
+            r = send_all => r,
+            () = cancellation_token.cancelled() => Err(crate::cancellation::error())
+        };
+
+        if let Err(e) = &result {
+            error!(
+                "Failed to push batches into streaming table: {}",
+                e.message()
+            );
+        }
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Marks a streaming table as finished so queries over it complete once buffered batches are consumed.
+///
+/// Pushes waiting for the running scan to make buffer space are delivered before the stream ends. Pushes waiting
+/// for a scan to start, because `capacity` batches are already kept, fail with `StreamClosed`.
+///
+/// # Safety
+/// - `table_ptr` must be a valid pointer returned by `datafusion_context_register_streaming_table`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_streaming_table_finish(
+    table_ptr: *mut StreamingTableWrapper,
+) -> ErrorCode {
+    let table = ffi_ref!(table_ptr);
+
+    debug!("Finishing streaming table {table_ptr:p}");
+
+    table.channel.finish();
+
+    ErrorCode::Ok
+}
@@ -0,0 +1,313 @@
+use arrow_array::ffi::FFI_ArrowSchema;
+use arrow_array::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::catalog::{Session, TableProvider};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::{Expr, TableProviderFilterPushDown, TableType};
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::stream::RecordBatchReceiverStream;
+use datafusion::physical_plan::streaming::{PartitionStream, StreamingTableExec};
+use datafusion_proto::logical_plan::DefaultLogicalExtensionCodec;
+use log::{debug, error};
+use prost::Message;
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// Returns the table schema by writing it to `schema_out`.
+pub type TableSchemaCallback =
+    unsafe extern "C" fn(user_data: isize, schema_out: *mut FFI_ArrowSchema) -> ErrorCode;
+
+/// Starts a scan of the table and writes the resulting batches to `stream_out`.
+///
+/// `projection_ptr` points to `projection_len` column indices, or is null when all columns are requested.
+/// `filters` contains a protobuf-encoded `LogicalExprNodeCollection` of filters the provider accepted.
+/// `limit` is the maximum number of rows needed, or negative when there is no limit.
+pub type TableScanCallback = unsafe extern "C" fn(
+    user_data: isize,
+    projection_ptr: *const u32,
+    projection_len: u32,
+    filters: BytesData,
+    limit: i64,
+    stream_out: *mut FFI_ArrowArrayStream,
+) -> ErrorCode;
+
+/// Reports for each filter in the protobuf-encoded `LogicalExprNodeCollection` whether it can be pushed down
+/// by writing `results_len` values to `results_ptr`: 0 unsupported, 1 inexact, 2 exact.
+pub type TableSupportsFiltersPushdownCallback = unsafe extern "C" fn(
+    user_data: isize,
+    filters: BytesData,
+    results_ptr: *mut i32,
+    results_len: u32,
+) -> ErrorCode;
+
+/// Releases the host state referenced by `user_data` once the table provider is dropped.
+pub type TableReleaseCallback = unsafe extern "C" fn(user_data: isize);
+
+/// Host callbacks implementing a table provider.
+#[repr(C)]
+#[derive(Debug)]
+pub struct TableProviderCallbacks {
+    pub user_data: isize,
+    pub schema: TableSchemaCallback,
+    pub scan: TableScanCallback,
+    pub supports_filters_pushdown: Option<TableSupportsFiltersPushdownCallback>,
+    pub release: Option<TableReleaseCallback>,
+}
+
+impl Drop for TableProviderCallbacks {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            debug!("Releasing host table provider {}", self.user_data);
+            unsafe { release(self.user_data) };
+        }
+    }
+}
+
+/// Table provider whose schema, scans and filter pushdown are answered by the host.
+#[derive(Debug)]
+struct HostTableProvider {
+    callbacks: Arc<TableProviderCallbacks>,
+    schema: SchemaRef,
+}
+
+impl HostTableProvider {
+    fn try_new(callbacks: TableProviderCallbacks) -> Result<Self> {
+        let mut ffi_schema = FFI_ArrowSchema::empty();
+        let code = unsafe { (callbacks.schema)(callbacks.user_data, &raw mut ffi_schema) };
+        if code != ErrorCode::Ok {
+            return Err(DataFusionError::Execution(format!(
+                "Host table provider schema callback failed with {code:?}"
+            )));
+        }
+
+        let schema = datafusion::arrow::datatypes::Schema::try_from(&ffi_schema)?;
+
+        Ok(Self {
+            callbacks: Arc::new(callbacks),
+            schema: Arc::new(schema),
+        })
+    }
+}
+
+#[async_trait]
+impl TableProvider for HostTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let projected_schema = match projection {
+            Some(p) => Arc::new(self.schema.project(p)?),
+            None => Arc::clone(&self.schema),
+        };
+
+        let projection = projection
+            .map(|p| {
+                p.iter()
+                    .map(|&i| u32::try_from(i))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+            .map_err(|e| DataFusionError::Internal(format!("Invalid projection index: {e}")))?;
+
+        let partition = HostScanPartition {
+            callbacks: Arc::clone(&self.callbacks),
+            schema: Arc::clone(&projected_schema),
+            projection,
+            filters: serialize_filters(filters.iter())?,
+            limit,
+        };
+
+        // The host applies the projection, so the partition already yields the projected schema
+        let exec = StreamingTableExec::try_new(
+            projected_schema,
+            vec![Arc::new(partition)],
+            None,
+            Vec::new(),
+            false,
+            limit,
+        )?;
+
+        Ok(Arc::new(exec))
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        let unsupported = vec![TableProviderFilterPushDown::Unsupported; filters.len()];
+
+        let Some(supports_filters_pushdown) = self.callbacks.supports_filters_pushdown else {
+            return Ok(unsupported);
+        };
+
+        // Filters that cannot be serialized are never offered to the host
+        let Ok(filters_bytes) = serialize_filters(filters.iter().copied()) else {
+            return Ok(unsupported);
+        };
+
+        let results_len = u32::try_from(filters.len())
+            .map_err(|e| DataFusionError::Internal(format!("Too many filters: {e}")))?;
+        let mut results = vec![0i32; filters.len()];
+        let code = unsafe {
+            supports_filters_pushdown(
+                self.callbacks.user_data,
+                BytesData::new(&filters_bytes),
+                results.as_mut_ptr(),
+                results_len,
+            )
+        };
+        if code != ErrorCode::Ok {
+            return Err(DataFusionError::Execution(format!(
+                "Host table provider filter pushdown callback failed with {code:?}"
+            )));
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|r| match r {
+                1 => TableProviderFilterPushDown::Inexact,
+                2 => TableProviderFilterPushDown::Exact,
+                _ => TableProviderFilterPushDown::Unsupported,
+            })
+            .collect())
+    }
+}
+
+/// Single partition of a host table scan, started when the plan is executed.
+#[derive(Debug)]
+struct HostScanPartition {
+    callbacks: Arc<TableProviderCallbacks>,
+    schema: SchemaRef,
+    projection: Option<Vec<u32>>,
+    filters: Vec<u8>,
+    limit: Option<usize>,
+}
+
+impl PartitionStream for HostScanPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = RecordBatchReceiverStream::builder(Arc::clone(&self.schema), 2);
+        let tx = builder.tx();
+
+        let callbacks = Arc::clone(&self.callbacks);
+        let schema = Arc::clone(&self.schema);
+        let projection = self.projection.clone();
+        let filters = self.filters.clone();
+        let limit = self.limit.and_then(|l| i64::try_from(l).ok()).unwrap_or(-1);
+
+        // The host reads batches synchronously, so the scan runs on a blocking thread
+        builder.spawn_blocking(move || {
+            let (projection_ptr, projection_len) = match &projection {
+                #[allow(clippy::cast_possible_truncation)]
+                Some(p) => (p.as_ptr(), p.len() as u32),
+                None => (std::ptr::null(), 0),
+            };
+
+            let mut ffi_stream = FFI_ArrowArrayStream::empty();
+            let code = unsafe {
+                (callbacks.scan)(
+                    callbacks.user_data,
+                    projection_ptr,
+                    projection_len,
+                    BytesData::new(&filters),
+                    limit,
+                    &raw mut ffi_stream,
+                )
+            };
+            if code != ErrorCode::Ok {
+                return Err(DataFusionError::Execution(format!(
+                    "Host table provider scan callback failed with {code:?}"
+                )));
+            }
+
+            let reader = ArrowArrayStreamReader::try_new(ffi_stream)?;
+            for batch in reader {
+                let batch = batch?.with_schema(Arc::clone(&schema))?;
+                if tx.blocking_send(Ok(batch)).is_err() {
+                    // The query was dropped or cancelled
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+        builder.build()
+    }
+}
+
+/// Serializes filter expressions as a protobuf-encoded `LogicalExprNodeCollection`.
+fn serialize_filters<'a>(filters: impl IntoIterator<Item = &'a Expr>) -> Result<Vec<u8>> {
+    let logical_expr_nodes = datafusion_proto::logical_plan::to_proto::serialize_exprs(
+        filters,
+        &DefaultLogicalExtensionCodec {},
+    )
+    .map_err(|e| DataFusionError::Plan(format!("Failed to serialize filters: {e}")))?;
+
+    Ok(
+        datafusion_proto::protobuf::LogicalExprNodeCollection { logical_expr_nodes }
+            .encode_to_vec(),
+    )
+}
+
+/// Registers a table whose schema, scans and filter pushdown are implemented by host callbacks.
+///
+/// The schema callback is invoked once during registration. Scans return record batches through
+/// the Arrow C Stream Interface and are read on a blocking thread while the query executes.
+/// The release callback is invoked when the table is dropped, including when registration fails.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with a bare or `catalog.schema.table` qualified name
+/// - `provider_callbacks` must contain function pointers that are valid to call from any thread until release
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_table_provider(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    provider_callbacks: TableProviderCallbacks,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    debug!("Registering host table provider '{table_ref}' on session {context_ptr:p}");
+
+    let result = HostTableProvider::try_new(provider_callbacks)
+        .and_then(|table| context.inner().register_table(&table_ref, Arc::new(table)))
+        .map_err(|e| {
+            error!("Failed to register host table provider '{table_ref}': {e}");
+            ErrorInfo::new(ErrorCode::TableRegistrationFailed, e)
+        })
+        .map(|_| ());
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
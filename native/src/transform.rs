@@ -0,0 +1,631 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::DFSchema;
+use datafusion::error::Result;
+use datafusion::logical_expr::expr_rewriter::normalize_col_with_schemas_and_ambiguity_check;
+use datafusion::logical_expr::{Expr, ExprSchemable, LogicalPlanBuilder, SortExpr};
+use datafusion::prelude::DataFrame;
+use datafusion_proto::logical_plan::DefaultLogicalExtensionCodec;
+use datafusion_proto::logical_plan::from_proto::{parse_expr, parse_exprs, parse_sorts};
+use datafusion_proto::protobuf::{
+    self, LogicalExprNode, LogicalExprNodeCollection, SortExprNodeCollection,
+};
+use log::{debug, error};
+use prost::Message;
+
+use crate::{BytesData, Callback, DataFrameWrapper, ErrorCode, ErrorInfo};
+
+/// Creates a new `DataFrame` with only the given expressions as columns.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `exprs_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.LogicalExprNodeCollection`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_select(
+    df_ptr: *mut DataFrameWrapper,
+    exprs_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let exprs_proto = match LogicalExprNodeCollection::decode(exprs_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode expressions protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Selecting expressions on DataFrame {df_ptr:p}");
+
+    let result = parse_expr_collection(df_wrapper, &exprs_proto)
+        .and_then(|exprs| transform(df_wrapper, |df| df.select(exprs)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with only the rows for which the predicate evaluates to true.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `predicate_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.LogicalExprNode`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_filter(
+    df_ptr: *mut DataFrameWrapper,
+    predicate_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let predicate_proto = match LogicalExprNode::decode(predicate_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode expression protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Filtering DataFrame {df_ptr:p}");
+
+    let result = parse_single_expr(df_wrapper, &predicate_proto)
+        .and_then(|predicate| transform(df_wrapper, |df| df.filter(predicate)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` that groups rows by the group expressions and computes the aggregate expressions.
+///
+/// An empty group expression collection aggregates all rows into a single row.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `group_exprs_bytes` and `aggr_exprs_bytes` must be valid `BytesData` containing protobuf-encoded `datafusion.LogicalExprNodeCollection`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_aggregate(
+    df_ptr: *mut DataFrameWrapper,
+    group_exprs_bytes: BytesData,
+    aggr_exprs_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let group_exprs_proto = match LogicalExprNodeCollection::decode(group_exprs_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode expressions protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+    let aggr_exprs_proto = match LogicalExprNodeCollection::decode(aggr_exprs_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode expressions protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Aggregating DataFrame {df_ptr:p}");
+
+    let result = parse_expr_collection(df_wrapper, &group_exprs_proto)
+        .and_then(|group_exprs| {
+            let aggr_exprs = parse_expr_collection(df_wrapper, &aggr_exprs_proto)?;
+            Ok((group_exprs, aggr_exprs))
+        })
+        .and_then(|(group_exprs, aggr_exprs)| {
+            transform(df_wrapper, |df| df.aggregate(group_exprs, aggr_exprs))
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` sorted by the given sort expressions.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `sort_exprs_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.SortExprNodeCollection`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_sort(
+    df_ptr: *mut DataFrameWrapper,
+    sort_exprs_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let sort_exprs_proto = match SortExprNodeCollection::decode(sort_exprs_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode sort expressions protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Sorting DataFrame {df_ptr:p}");
+
+    let result = parse_sort_collection(df_wrapper, &sort_exprs_proto)
+        .and_then(|sort_exprs| transform(df_wrapper, |df| df.sort(sort_exprs)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` that skips `skip` rows and returns at most `fetch` rows.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `skip`: Number of rows to skip
+/// - `fetch`: Maximum number of rows to return (negative = no limit)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_limit(
+    df_ptr: *mut DataFrameWrapper,
+    skip: u64,
+    fetch: i64,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Limiting DataFrame {df_ptr:p} with skip={skip}, fetch={fetch}");
+
+    #[allow(clippy::cast_possible_truncation)]
+    let skip = skip as usize;
+    let fetch = usize::try_from(fetch).ok();
+    let result = transform(df_wrapper, |df| df.limit(skip, fetch));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with a column computed from an expression, replacing any existing column with the same name.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `expr_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.LogicalExprNode`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_with_column(
+    df_ptr: *mut DataFrameWrapper,
+    name_ptr: *const std::ffi::c_char,
+    expr_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+    let expr_proto = match LogicalExprNode::decode(expr_bytes.as_slice()) {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode expression protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!("Adding column '{name}' to DataFrame {df_ptr:p}");
+
+    let result = parse_single_expr(df_wrapper, &expr_proto)
+        .and_then(|expr| transform(df_wrapper, |df| df.with_column(&name, expr)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with a column renamed.
+///
+/// Renaming a column that does not exist is a no-op.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `old_name_ptr` must be a valid null-terminated UTF-8 string with a bare or qualified column name
+/// - `new_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_with_column_renamed(
+    df_ptr: *mut DataFrameWrapper,
+    old_name_ptr: *const std::ffi::c_char,
+    new_name_ptr: *const std::ffi::c_char,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let old_name = ffi_cstr_to_string!(old_name_ptr);
+    let new_name = ffi_cstr_to_string!(new_name_ptr);
+
+    debug!("Renaming column '{old_name}' to '{new_name}' on DataFrame {df_ptr:p}");
+
+    let result = transform(df_wrapper, |df| df.with_column_renamed(old_name, &new_name));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` without the given columns.
+///
+/// Columns that do not exist are ignored.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `names_ptr` must point to `names_len` valid null-terminated UTF-8 strings with bare or qualified column names
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_drop_columns(
+    df_ptr: *mut DataFrameWrapper,
+    names_ptr: *const *const std::ffi::c_char,
+    names_len: u32,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let names = ffi_cstr_array_to_vec!(names_ptr, names_len);
+
+    debug!("Dropping columns {names:?} from DataFrame {df_ptr:p}");
+
+    let result = transform(df_wrapper, |df| df.drop_columns(&names));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` without duplicate rows.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_distinct(
+    df_ptr: *mut DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    debug!("Applying distinct to DataFrame {df_ptr:p}");
+
+    let result = transform(df_wrapper, DataFrame::distinct);
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` by joining two `DataFrame`s on equality of key columns.
+///
+/// `join_type` is a `datafusion.JoinType` value. `left_cols` and `right_cols` must have the same length,
+/// and the optional filter is applied to candidate row pairs in addition to the key equality.
+/// Columns of the filter are resolved against both `DataFrame`s and must be qualified when the name is ambiguous.
+/// The right `DataFrame` should be created from the same `SessionContext` as the left one.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions
+/// - `left_cols_ptr` must point to `left_cols_len` valid null-terminated UTF-8 strings with column names of the left `DataFrame`
+/// - `right_cols_ptr` must point to `right_cols_len` valid null-terminated UTF-8 strings with column names of the right `DataFrame`
+/// - `filter_bytes` must be a valid `BytesData` containing a protobuf-encoded `datafusion.LogicalExprNode`, or have a null data pointer for no filter
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_join(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    join_type: i32,
+    left_cols_ptr: *const *const std::ffi::c_char,
+    left_cols_len: u32,
+    right_cols_ptr: *const *const std::ffi::c_char,
+    right_cols_len: u32,
+    filter_bytes: BytesData,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+    let Ok(join_type) = protobuf::JoinType::try_from(join_type) else {
+        error!("Invalid join type: {join_type}");
+        return ErrorCode::InvalidArgument;
+    };
+    let left_cols = ffi_cstr_array_to_vec!(left_cols_ptr, left_cols_len);
+    let right_cols = ffi_cstr_array_to_vec!(right_cols_ptr, right_cols_len);
+    let filter_proto = match filter_bytes
+        .as_opt_slice()
+        .map(LogicalExprNode::decode)
+        .transpose()
+    {
+        Ok(node) => node,
+        Err(e) => {
+            error!("Failed to decode expression protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!(
+        "Joining DataFrame {left_ptr:p} with {right_ptr:p} using {}",
+        join_type.as_str_name()
+    );
+
+    let result = filter_proto
+        .map(|node| {
+            let filter = parse_single_expr(left_wrapper, &node)?;
+            resolve_join_filter(
+                filter,
+                left_wrapper.inner().schema(),
+                right_wrapper.inner().schema(),
+            )
+        })
+        .transpose()
+        .and_then(|filter| {
+            let left_cols = left_cols.iter().map(String::as_str).collect::<Vec<_>>();
+            let right_cols = right_cols.iter().map(String::as_str).collect::<Vec<_>>();
+            transform(left_wrapper, |df| {
+                df.join(
+                    right_wrapper.clone_inner(),
+                    join_type.into(),
+                    &left_cols,
+                    &right_cols,
+                    filter,
+                )
+            })
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with every combination of rows from two `DataFrame`s.
+///
+/// The right `DataFrame` should be created from the same `SessionContext` as the left one.
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_cross_join(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    debug!("Cross joining DataFrame {left_ptr:p} with {right_ptr:p}");
+
+    let result = transform(left_wrapper, |df| {
+        let (state, plan) = df.into_parts();
+        let plan = LogicalPlanBuilder::from(plan)
+            .cross_join(right_wrapper.inner().logical_plan().clone())?
+            .build()?;
+        Ok(DataFrame::new(state, plan))
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with the rows of both `DataFrame`s, which must have the same number of columns.
+///
+/// With `distinct` duplicate rows are removed (`UNION`), otherwise all rows are kept (`UNION ALL`).
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_union(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    distinct: bool,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    debug!("Union of DataFrame {left_ptr:p} with {right_ptr:p}, distinct={distinct}");
+
+    let result = transform(left_wrapper, |df| {
+        if distinct {
+            df.union_distinct(right_wrapper.clone_inner())
+        } else {
+            df.union(right_wrapper.clone_inner())
+        }
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with the rows present in both `DataFrame`s.
+///
+/// With `distinct` duplicate rows are removed (`INTERSECT`), otherwise they are kept (`INTERSECT ALL`).
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_intersect(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    distinct: bool,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    debug!("Intersect of DataFrame {left_ptr:p} with {right_ptr:p}, distinct={distinct}");
+
+    let result = transform(left_wrapper, |df| {
+        if distinct {
+            df.intersect_distinct(right_wrapper.clone_inner())
+        } else {
+            df.intersect(right_wrapper.clone_inner())
+        }
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Creates a new `DataFrame` with the rows of the left `DataFrame` that are not present in the right one.
+///
+/// With `distinct` duplicate rows are removed (`EXCEPT`), otherwise they are kept (`EXCEPT ALL`).
+///
+/// This is a synchronous operation. The callback is invoked with a pointer to the new `DataFrameWrapper`.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_except(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    distinct: bool,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    debug!("Except of DataFrame {left_ptr:p} with {right_ptr:p}, distinct={distinct}");
+
+    let result = transform(left_wrapper, |df| {
+        if distinct {
+            df.except_distinct(right_wrapper.clone_inner())
+        } else {
+            df.except(right_wrapper.clone_inner())
+        }
+    });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Applies `f` to a clone of the wrapped `DataFrame` and wraps the result in a new `DataFrameWrapper`.
+pub(crate) fn transform(
+    df_wrapper: &DataFrameWrapper,
+    f: impl FnOnce(DataFrame) -> Result<DataFrame>,
+) -> Result<*mut DataFrameWrapper, ErrorInfo> {
+    f(df_wrapper.clone_inner())
+        .map(|df| crate::dataframe_to_ptr(df_wrapper.runtime(), df))
+        .map_err(|e| {
+            error!("Failed to transform DataFrame: {e}");
+            ErrorInfo::from_datafusion(ErrorCode::DataFrameError, &e)
+        })
+}
+
+// Functions registered on the session, including host UDFs, are resolved through the task context
+fn parse_single_expr(
+    df_wrapper: &DataFrameWrapper,
+    expr: &LogicalExprNode,
+) -> Result<Expr, ErrorInfo> {
+    parse_expr(
+        expr,
+        &df_wrapper.inner().task_ctx(),
+        &DefaultLogicalExtensionCodec {},
+    )
+    .map_err(invalid_expression)
+}
+
+fn parse_expr_collection(
+    df_wrapper: &DataFrameWrapper,
+    exprs: &LogicalExprNodeCollection,
+) -> Result<Vec<Expr>, ErrorInfo> {
+    parse_exprs(
+        &exprs.logical_expr_nodes,
+        &df_wrapper.inner().task_ctx(),
+        &DefaultLogicalExtensionCodec {},
+    )
+    .map_err(invalid_expression)
+}
+
+fn parse_sort_collection(
+    df_wrapper: &DataFrameWrapper,
+    sort_exprs: &SortExprNodeCollection,
+) -> Result<Vec<SortExpr>, ErrorInfo> {
+    parse_sorts(
+        &sort_exprs.sort_expr_nodes,
+        &df_wrapper.inner().task_ctx(),
+        &DefaultLogicalExtensionCodec {},
+    )
+    .map_err(invalid_expression)
+}
+
+/// Resolves the columns of a join filter against the schemas of both inputs,
+/// so that unknown or ambiguous columns and non-boolean filters are reported as invalid arguments.
+fn resolve_join_filter(filter: Expr, left: &DFSchema, right: &DFSchema) -> Result<Expr, ErrorInfo> {
+    let filter = normalize_col_with_schemas_and_ambiguity_check(filter, &[&[left, right]], &[])
+        .map_err(invalid_expression)?;
+
+    let mut joined = left.clone();
+    joined.merge(right);
+    match filter.get_type(&joined).map_err(invalid_expression)? {
+        DataType::Boolean | DataType::Null => Ok(filter),
+        data_type => Err(invalid_expression(format!(
+            "join filter must be a boolean expression, got {data_type}"
+        ))),
+    }
+}
+
+fn invalid_expression(e: impl std::fmt::Display) -> ErrorInfo {
+    ErrorInfo::new(
+        ErrorCode::InvalidArgument,
+        format!("Invalid expression: {e}"),
+    )
+}
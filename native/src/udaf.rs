@@ -0,0 +1,332 @@
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{Array, ArrayRef, StructArray};
+use datafusion::arrow::datatypes::{DataType, FieldRef, Fields};
+use datafusion::common::ScalarValue;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDF, AggregateUDFImpl, Signature};
+use log::{debug, error};
+use prost::Message;
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::udf::{UdfReleaseCallback, export_arrays, export_columns, host_error, import_array};
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, SessionContextWrapper, mappers, proto};
+
+/// Creates a new host accumulator and writes its handle to `accumulator_out`.
+pub type CreateAccumulatorCallback =
+    unsafe extern "C" fn(user_data: isize, accumulator_out: *mut isize) -> ErrorCode;
+
+/// Updates an accumulator with a batch of rows, or merges a batch of partial states into it.
+///
+/// `schema` and `array` describe a struct array whose children are the argument or state columns;
+/// both are owned by the library and only valid for the duration of the call.
+pub type AccumulatorBatchCallback = unsafe extern "C" fn(
+    accumulator: isize,
+    schema: *const FFI_ArrowSchema,
+    array: *const FFI_ArrowArray,
+) -> ErrorCode;
+
+/// Writes the accumulator's state as a struct array with one row and the declared state fields.
+pub type AccumulatorStateCallback =
+    unsafe extern "C" fn(accumulator: isize, state_out: *mut FFI_ArrowArray) -> ErrorCode;
+
+/// Writes the aggregate result as an array with one value of the declared return type.
+pub type AccumulatorEvaluateCallback =
+    unsafe extern "C" fn(accumulator: isize, result_out: *mut FFI_ArrowArray) -> ErrorCode;
+
+/// Releases a host accumulator once it is no longer used.
+pub type AccumulatorReleaseCallback = unsafe extern "C" fn(accumulator: isize);
+
+/// Host callbacks implementing an aggregate UDF.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AggregateUdfCallbacks {
+    pub user_data: isize,
+    pub create_accumulator: CreateAccumulatorCallback,
+    pub update_batch: AccumulatorBatchCallback,
+    pub merge_batch: AccumulatorBatchCallback,
+    pub state: AccumulatorStateCallback,
+    pub evaluate: AccumulatorEvaluateCallback,
+    pub release_accumulator: AccumulatorReleaseCallback,
+    pub release: Option<UdfReleaseCallback>,
+}
+
+impl Drop for AggregateUdfCallbacks {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            debug!("Releasing host aggregate UDF {}", self.user_data);
+            unsafe { release(self.user_data) };
+        }
+    }
+}
+
+/// Aggregate UDF whose accumulators live on the host.
+#[derive(Debug)]
+struct HostAggregateUdf {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    state_fields: Fields,
+    callbacks: Arc<AggregateUdfCallbacks>,
+}
+
+impl PartialEq for HostAggregateUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.signature == other.signature
+            && self.return_type == other.return_type
+            && self.state_fields == other.state_fields
+            && self.callbacks.user_data == other.callbacks.user_data
+    }
+}
+
+impl Eq for HostAggregateUdf {}
+
+impl Hash for HostAggregateUdf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+        self.return_type.hash(state);
+        self.state_fields.hash(state);
+        self.callbacks.user_data.hash(state);
+    }
+}
+
+impl AggregateUDFImpl for HostAggregateUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        if acc_args.is_distinct {
+            return Err(DataFusionError::NotImplemented(format!(
+                "DISTINCT is not supported by host aggregate function '{}'",
+                self.name
+            )));
+        }
+
+        let mut handle = 0;
+        let code = unsafe {
+            (self.callbacks.create_accumulator)(self.callbacks.user_data, &raw mut handle)
+        };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        Ok(Box::new(HostAccumulator {
+            name: self.name.clone(),
+            handle,
+            return_type: self.return_type.clone(),
+            state_fields: self.state_fields.clone(),
+            callbacks: Arc::clone(&self.callbacks),
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        // State names are qualified by the aggregate name so the same function can be used more than once per query
+        Ok(self
+            .state_fields
+            .iter()
+            .map(|f| {
+                Arc::new(
+                    f.as_ref()
+                        .clone()
+                        .with_name(format_state_name(args.name, f.name())),
+                )
+            })
+            .collect())
+    }
+}
+
+/// Accumulator whose state is held by the host under `handle`.
+#[derive(Debug)]
+struct HostAccumulator {
+    name: String,
+    handle: isize,
+    return_type: DataType,
+    state_fields: Fields,
+    callbacks: Arc<AggregateUdfCallbacks>,
+}
+
+impl HostAccumulator {
+    fn call_batch(
+        &self,
+        batch_callback: AccumulatorBatchCallback,
+        ffi_schema: &FFI_ArrowSchema,
+        ffi_array: &FFI_ArrowArray,
+    ) -> Result<()> {
+        let code = unsafe { batch_callback(self.handle, ffi_schema, ffi_array) };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for HostAccumulator {
+    fn drop(&mut self) {
+        unsafe { (self.callbacks.release_accumulator)(self.handle) };
+    }
+}
+
+impl Accumulator for HostAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let num_rows = values.first().map_or(0, Array::len);
+        let (ffi_schema, ffi_array) = export_arrays(values, num_rows)?;
+        self.call_batch(self.callbacks.update_batch, &ffi_schema, &ffi_array)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let num_rows = states.first().map_or(0, Array::len);
+        let (ffi_schema, ffi_array) = export_columns(self.state_fields.clone(), states, num_rows)?;
+        self.call_batch(self.callbacks.merge_batch, &ffi_schema, &ffi_array)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let mut state_array = FFI_ArrowArray::empty();
+        let code = unsafe { (self.callbacks.state)(self.handle, &raw mut state_array) };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        let state = import_array(state_array, &DataType::Struct(self.state_fields.clone()))?;
+        let state = state
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .filter(|s| s.len() == 1)
+            .ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "Host function '{}' must return a state with exactly one row",
+                    self.name
+                ))
+            })?;
+
+        state
+            .columns()
+            .iter()
+            .map(|c| ScalarValue::try_from_array(c, 0))
+            .collect()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mut result_array = FFI_ArrowArray::empty();
+        let code = unsafe { (self.callbacks.evaluate)(self.handle, &raw mut result_array) };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        let result = import_array(result_array, &self.return_type)?;
+        if result.len() != 1 {
+            return Err(DataFusionError::Execution(format!(
+                "Host function '{}' must return exactly one value, got {}",
+                self.name,
+                result.len()
+            )));
+        }
+
+        ScalarValue::try_from_array(&result, 0)
+    }
+
+    fn size(&self) -> usize {
+        // Memory held by the host is not tracked
+        std::mem::size_of_val(self)
+    }
+}
+
+/// Registers an aggregate UDF whose accumulators are implemented by host callbacks.
+///
+/// The release callback is invoked when the function is dropped, including when registration fails.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `signature_bytes` must be a valid `BytesData` containing a protobuf-encoded `AggregateUdfSignature`
+/// - `udaf_callbacks` must contain function pointers that are valid to call from any thread until release
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_aggregate_udf(
+    context_ptr: *mut SessionContextWrapper,
+    signature_bytes: BytesData,
+    udaf_callbacks: AggregateUdfCallbacks,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let signature_proto = match proto::AggregateUdfSignature::decode(signature_bytes.as_slice()) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Failed to decode aggregate UDF signature protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!(
+        "Registering aggregate UDF '{}' on session {context_ptr:p}",
+        signature_proto.name
+    );
+
+    let result = from_proto_aggregate_udf(&signature_proto, udaf_callbacks)
+        .map(|udaf| {
+            context
+                .inner()
+                .register_udaf(AggregateUDF::new_from_impl(udaf));
+        })
+        .map_err(|e| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Invalid aggregate UDF signature: {e}"),
+            )
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn from_proto_aggregate_udf(
+    signature: &proto::AggregateUdfSignature,
+    callbacks: AggregateUdfCallbacks,
+) -> anyhow::Result<HostAggregateUdf> {
+    let arg_types = signature
+        .arg_types
+        .iter()
+        .map(|t| mappers::from_proto_arrow_type(Some(t)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let return_type = mappers::from_proto_arrow_type(signature.return_type.as_ref())?;
+    let state_fields = signature
+        .state_fields
+        .iter()
+        .map(datafusion::arrow::datatypes::Field::try_from)
+        .collect::<Result<Fields, _>>()
+        .map_err(|e| anyhow::anyhow!("Failed to parse state fields: {e}"))?;
+    if state_fields.is_empty() {
+        anyhow::bail!("At least one state field is required");
+    }
+    let volatility = mappers::from_proto_volatility(signature.volatility)?;
+
+    Ok(HostAggregateUdf {
+        name: signature.name.clone(),
+        signature: Signature::exact(arg_types, volatility),
+        return_type,
+        state_fields,
+        callbacks: Arc::new(callbacks),
+    })
+}
@@ -0,0 +1,280 @@
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{Array, ArrayRef, StructArray};
+use datafusion::arrow::datatypes::{DataType, Field, Fields};
+use datafusion::arrow::error::ArrowError;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature,
+};
+use log::{debug, error};
+use prost::Message;
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, SessionContextWrapper, mappers, proto};
+
+/// Evaluates a scalar UDF over a batch of rows.
+///
+/// `args_schema` and `args_array` describe a struct array whose children are the argument columns;
+/// both are owned by the library and only valid for the duration of the call.
+/// The result array must have the declared return type and one value per row.
+pub type ScalarUdfInvokeCallback = unsafe extern "C" fn(
+    user_data: isize,
+    args_schema: *const FFI_ArrowSchema,
+    args_array: *const FFI_ArrowArray,
+    result_out: *mut FFI_ArrowArray,
+) -> ErrorCode;
+
+/// Releases the host state referenced by `user_data` once the function is dropped.
+pub type UdfReleaseCallback = unsafe extern "C" fn(user_data: isize);
+
+/// Host callbacks implementing a scalar UDF.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ScalarUdfCallbacks {
+    pub user_data: isize,
+    pub invoke: ScalarUdfInvokeCallback,
+    pub release: Option<UdfReleaseCallback>,
+}
+
+impl Drop for ScalarUdfCallbacks {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            debug!("Releasing host scalar UDF {}", self.user_data);
+            unsafe { release(self.user_data) };
+        }
+    }
+}
+
+/// Scalar UDF evaluated by the host.
+#[derive(Debug)]
+struct HostScalarUdf {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    callbacks: Arc<ScalarUdfCallbacks>,
+}
+
+impl PartialEq for HostScalarUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.signature == other.signature
+            && self.return_type == other.return_type
+            && self.callbacks.user_data == other.callbacks.user_data
+    }
+}
+
+impl Eq for HostScalarUdf {}
+
+impl Hash for HostScalarUdf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+        self.return_type.hash(state);
+        self.callbacks.user_data.hash(state);
+    }
+}
+
+impl ScalarUDFImpl for HostScalarUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let arrays = args
+            .args
+            .iter()
+            .map(|arg| arg.to_array(args.number_rows))
+            .collect::<Result<Vec<_>>>()?;
+        let (ffi_schema, ffi_array) = export_arrays(&arrays, args.number_rows)?;
+
+        let mut result_array = FFI_ArrowArray::empty();
+        let code = unsafe {
+            (self.callbacks.invoke)(
+                self.callbacks.user_data,
+                &raw const ffi_schema,
+                &raw const ffi_array,
+                &raw mut result_array,
+            )
+        };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        let result = import_array(result_array, &self.return_type)?;
+        if result.len() != args.number_rows {
+            return Err(DataFusionError::Execution(format!(
+                "Host function '{}' returned {} values for {} rows",
+                self.name,
+                result.len(),
+                args.number_rows
+            )));
+        }
+
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+/// Exports argument columns as a struct array with `num_rows` rows through the Arrow C Data Interface.
+pub(crate) fn export_arrays(
+    arrays: &[ArrayRef],
+    num_rows: usize,
+) -> Result<(FFI_ArrowSchema, FFI_ArrowArray)> {
+    let fields = arrays
+        .iter()
+        .enumerate()
+        .map(|(i, a)| Field::new(format!("arg{i}"), a.data_type().clone(), true))
+        .collect::<Fields>();
+    export_columns(fields, arrays, num_rows)
+}
+
+/// Exports columns described by `fields` as a struct array with `num_rows` rows through the Arrow C Data Interface.
+pub(crate) fn export_columns(
+    fields: Fields,
+    arrays: &[ArrayRef],
+    num_rows: usize,
+) -> Result<(FFI_ArrowSchema, FFI_ArrowArray)> {
+    let st = StructArray::try_new_with_length(fields, arrays.to_vec(), None, num_rows)?;
+
+    let ffi_schema = FFI_ArrowSchema::try_from(st.data_type())?;
+    let ffi_array = FFI_ArrowArray::new(&st.to_data());
+
+    Ok((ffi_schema, ffi_array))
+}
+
+/// Imports an array of the given type returned by the host through the Arrow C Data Interface.
+pub(crate) fn import_array(ffi_array: FFI_ArrowArray, data_type: &DataType) -> Result<ArrayRef> {
+    check_ffi_children(&ffi_array, data_type)?;
+    let data = unsafe { arrow_array::ffi::from_ffi_and_data_type(ffi_array, data_type.clone()) }?;
+    // The import trusts the host for buffer sizes and contents, so check them before any kernel reads the array
+    data.validate_full().map_err(|e| {
+        DataFusionError::Execution(format!("Host returned an invalid {data_type} array: {e}"))
+    })?;
+    Ok(arrow_array::make_array(data))
+}
+
+/// Checks that an array received through the Arrow C Data Interface has the children of `data_type`.
+///
+/// The import asserts on a mismatch, which would abort the host instead of failing the call.
+pub(crate) fn check_ffi_children(
+    ffi_array: &FFI_ArrowArray,
+    data_type: &DataType,
+) -> std::result::Result<(), ArrowError> {
+    let child_types = match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::ListView(field)
+        | DataType::LargeListView(field)
+        | DataType::Map(field, _) => vec![field.data_type()],
+        DataType::Struct(fields) => fields.iter().map(|f| f.data_type()).collect(),
+        DataType::Union(fields, _) => fields.iter().map(|(_, f)| f.data_type()).collect(),
+        DataType::RunEndEncoded(run_ends, values) => vec![run_ends.data_type(), values.data_type()],
+        _ => Vec::new(),
+    };
+
+    if ffi_array.num_children() != child_types.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected {} child arrays for {data_type}, got {}",
+            child_types.len(),
+            ffi_array.num_children()
+        )));
+    }
+    for (i, child_type) in child_types.into_iter().enumerate() {
+        check_ffi_children(ffi_array.child(i), child_type)?;
+    }
+
+    match (data_type, ffi_array.dictionary()) {
+        (DataType::Dictionary(_, value_type), Some(dictionary)) => {
+            check_ffi_children(dictionary, value_type)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Creates an error for a host callback of function `name` that returned a non-Ok code.
+pub(crate) fn host_error(name: &str, code: ErrorCode) -> DataFusionError {
+    DataFusionError::Execution(format!("Host function '{name}' failed with {code:?}"))
+}
+
+/// Registers a scalar UDF whose implementation is a host callback.
+///
+/// The release callback is invoked when the function is dropped, including when registration fails.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `signature_bytes` must be a valid `BytesData` containing a protobuf-encoded `ScalarUdfSignature`
+/// - `udf_callbacks` must contain function pointers that are valid to call from any thread until release
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_scalar_udf(
+    context_ptr: *mut SessionContextWrapper,
+    signature_bytes: BytesData,
+    udf_callbacks: ScalarUdfCallbacks,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let signature_proto = match proto::ScalarUdfSignature::decode(signature_bytes.as_slice()) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Failed to decode scalar UDF signature protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!(
+        "Registering scalar UDF '{}' on session {context_ptr:p}",
+        signature_proto.name
+    );
+
+    let result = from_proto_scalar_udf(&signature_proto, udf_callbacks)
+        .map(|udf| context.inner().register_udf(ScalarUDF::new_from_impl(udf)))
+        .map_err(|e| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Invalid scalar UDF signature: {e}"),
+            )
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn from_proto_scalar_udf(
+    signature: &proto::ScalarUdfSignature,
+    callbacks: ScalarUdfCallbacks,
+) -> anyhow::Result<HostScalarUdf> {
+    let arg_types = signature
+        .arg_types
+        .iter()
+        .map(|t| mappers::from_proto_arrow_type(Some(t)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let return_type = mappers::from_proto_arrow_type(signature.return_type.as_ref())?;
+    let volatility = mappers::from_proto_volatility(signature.volatility)?;
+
+    Ok(HostScalarUdf {
+        name: signature.name.clone(),
+        signature: Signature::exact(arg_types, volatility),
+        return_type,
+        callbacks: Arc::new(callbacks),
+    })
+}
@@ -0,0 +1,255 @@
+use arrow_array::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use async_trait::async_trait;
+use datafusion::arrow::array::{RecordBatch, RecordBatchReader};
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::catalog::{Session, TableFunctionImpl, TableProvider};
+use datafusion::common::DFSchema;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::simplify::SimplifyContext;
+use datafusion::logical_expr::{Expr, TableType};
+use datafusion::optimizer::simplify_expressions::ExprSimplifier;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::stream::RecordBatchReceiverStream;
+use datafusion::physical_plan::streaming::{PartitionStream, StreamingTableExec};
+use log::{debug, error};
+use prost::Message;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use crate::udf::{UdfReleaseCallback, host_error};
+use crate::{BytesData, Callback, ErrorCode, SessionContextWrapper, mappers, proto};
+
+/// Evaluates a table function call and writes its schema and batches to `stream_out`.
+///
+/// `args` contains a protobuf-encoded `TableFunctionArgs` with the literal call arguments.
+/// The stream is read later, when the query executes, and possibly from another thread.
+pub type TableFunctionCallCallback = unsafe extern "C" fn(
+    user_data: isize,
+    args: BytesData,
+    stream_out: *mut FFI_ArrowArrayStream,
+) -> ErrorCode;
+
+/// Host callbacks implementing a user-defined table function.
+#[repr(C)]
+#[derive(Debug)]
+pub struct TableFunctionCallbacks {
+    pub user_data: isize,
+    pub call: TableFunctionCallCallback,
+    pub release: Option<UdfReleaseCallback>,
+}
+
+impl Drop for TableFunctionCallbacks {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            debug!("Releasing host table function {}", self.user_data);
+            unsafe { release(self.user_data) };
+        }
+    }
+}
+
+/// Table function whose result is produced by the host when the call is planned.
+#[derive(Debug)]
+struct HostTableFunction {
+    name: String,
+    callbacks: TableFunctionCallbacks,
+}
+
+impl TableFunctionImpl for HostTableFunction {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        // Constant arguments such as casts or arithmetic are folded to literals first
+        let simplifier = ExprSimplifier::new(SimplifyContext::default().with_current_time());
+        let args = args
+            .iter()
+            .map(|arg| {
+                let arg = simplifier.coerce(arg.clone(), &DFSchema::empty())?;
+                match simplifier.simplify(arg)? {
+                    Expr::Literal(value, metadata) => {
+                        mappers::to_proto_scalar_value_and_metadata(&value, metadata.as_ref())
+                            .map_err(|e| DataFusionError::Plan(e.to_string()))
+                    }
+                    arg => Err(DataFusionError::Plan(format!(
+                        "Table function '{}' only accepts constant arguments, got {arg}",
+                        self.name
+                    ))),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let args_bytes = proto::TableFunctionArgs { args }.encode_to_vec();
+
+        let mut ffi_stream = FFI_ArrowArrayStream::empty();
+        let code = unsafe {
+            (self.callbacks.call)(
+                self.callbacks.user_data,
+                BytesData::new(&args_bytes),
+                &raw mut ffi_stream,
+            )
+        };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        let reader = ArrowArrayStreamReader::try_new(ffi_stream)?;
+        Ok(Arc::new(HostTableFunctionResult {
+            partition: Arc::new(HostTableFunctionPartition {
+                name: self.name.clone(),
+                schema: reader.schema(),
+                batches: Arc::new(Mutex::new(HostTableFunctionBatches::Unread(reader))),
+            }),
+        }))
+    }
+}
+
+/// Result of a host table function call.
+#[derive(Debug)]
+struct HostTableFunctionResult {
+    partition: Arc<HostTableFunctionPartition>,
+}
+
+#[async_trait]
+impl TableProvider for HostTableFunctionResult {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.partition.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Temporary
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let partition: Arc<dyn PartitionStream> = self.partition.clone();
+        let exec = StreamingTableExec::try_new(
+            Arc::clone(&self.partition.schema),
+            vec![partition],
+            projection,
+            Vec::new(),
+            false,
+            limit,
+        )?;
+
+        Ok(Arc::new(exec))
+    }
+}
+
+/// Batches of a host table function call, read from the host stream when the result is first executed.
+#[derive(Debug)]
+enum HostTableFunctionBatches {
+    Unread(ArrowArrayStreamReader),
+    Read(Vec<RecordBatch>),
+    Failed(String),
+}
+
+/// Single partition of a host table function result, shared by every scan of the same call.
+#[derive(Debug)]
+struct HostTableFunctionPartition {
+    name: String,
+    schema: SchemaRef,
+    batches: Arc<Mutex<HostTableFunctionBatches>>,
+}
+
+impl PartitionStream for HostTableFunctionPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = RecordBatchReceiverStream::builder(Arc::clone(&self.schema), 2);
+        let tx = builder.tx();
+
+        let name = self.name.clone();
+        let batches = Arc::clone(&self.batches);
+
+        // The host reads batches synchronously, so the stream is read on a blocking thread
+        builder.spawn_blocking(move || {
+            for batch in read_batches(&name, &batches)? {
+                if tx.blocking_send(Ok(batch)).is_err() {
+                    // The query was dropped or cancelled
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+        builder.build()
+    }
+}
+
+/// Returns the batches of a host table function call, reading them from the host stream on the first call.
+fn read_batches(name: &str, batches: &Mutex<HostTableFunctionBatches>) -> Result<Vec<RecordBatch>> {
+    let mut batches = batches
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let read = match &mut *batches {
+        HostTableFunctionBatches::Read(read) => return Ok(read.clone()),
+        HostTableFunctionBatches::Failed(message) => {
+            return Err(DataFusionError::Execution(message.clone()));
+        }
+        HostTableFunctionBatches::Unread(reader) => reader.collect::<Result<Vec<_>, _>>(),
+    };
+
+    match read {
+        Ok(read) => {
+            *batches = HostTableFunctionBatches::Read(read.clone());
+            Ok(read)
+        }
+        Err(e) => {
+            // A partially read stream cannot be read again, so later scans fail the same way
+            let message = format!("Failed to read the result of table function '{name}': {e}");
+            *batches = HostTableFunctionBatches::Failed(message.clone());
+            Err(DataFusionError::Execution(message))
+        }
+    }
+}
+
+/// Registers a table function callable from SQL whose result is produced by a host callback.
+///
+/// The callback is invoked while the query is planned, including for `EXPLAIN`, since the stream provides the
+/// result schema. Its batches are read on a blocking thread when the query first executes, and are then kept in
+/// memory so that the same call can be scanned more than once.
+/// Calls accept constant arguments only, which are folded to literals before the callback is invoked.
+/// The release callback is invoked when the function is dropped.
+///
+/// This is a synchronous operation.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `udtf_callbacks` must contain function pointers that are valid to call from any thread until release
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_table_function(
+    context_ptr: *mut SessionContextWrapper,
+    name_ptr: *const std::ffi::c_char,
+    udtf_callbacks: TableFunctionCallbacks,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+
+    debug!("Registering table function '{name}' on session {context_ptr:p}");
+
+    context.inner().register_udtf(
+        &name,
+        Arc::new(HostTableFunction {
+            name: name.clone(),
+            callbacks: udtf_callbacks,
+        }),
+    );
+
+    crate::invoke_callback_null_result(callback, user_data);
+
+    ErrorCode::Ok
+}
@@ -0,0 +1,298 @@
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{Array, ArrayRef};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, WindowUDF, WindowUDFImpl};
+use log::{debug, error};
+use prost::Message;
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::udf::{UdfReleaseCallback, export_arrays, host_error, import_array};
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, SessionContextWrapper, mappers, proto};
+
+/// Evaluates a window UDF over a whole window partition.
+///
+/// `args_schema` and `args_array` describe a struct array whose children are the argument columns
+/// in the partition's `ORDER BY` order; both are owned by the library and only valid for the duration of the call.
+/// The result array must have the declared return type and one value per row.
+pub type WindowEvaluateAllCallback = unsafe extern "C" fn(
+    user_data: isize,
+    args_schema: *const FFI_ArrowSchema,
+    args_array: *const FFI_ArrowArray,
+    result_out: *mut FFI_ArrowArray,
+) -> ErrorCode;
+
+/// Evaluates a window UDF over a window partition of `num_rows` rows using only the row ranks.
+///
+/// `ranks_ptr` points to `ranks_len` ranges of rows that are peers under the `ORDER BY` clause.
+/// The result array must have the declared return type and one value per row.
+pub type WindowEvaluateAllWithRankCallback = unsafe extern "C" fn(
+    user_data: isize,
+    num_rows: u64,
+    ranks_ptr: *const RankRange,
+    ranks_len: u64,
+    result_out: *mut FFI_ArrowArray,
+) -> ErrorCode;
+
+/// Half-open range `[start, end)` of rows that share the same rank.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RankRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// Host callbacks implementing a window UDF.
+///
+/// When `evaluate_all_with_rank` is set the function is evaluated from row ranks only,
+/// otherwise `evaluate_all` receives the argument columns.
+#[repr(C)]
+#[derive(Debug)]
+pub struct WindowUdfCallbacks {
+    pub user_data: isize,
+    pub evaluate_all: Option<WindowEvaluateAllCallback>,
+    pub evaluate_all_with_rank: Option<WindowEvaluateAllWithRankCallback>,
+    pub release: Option<UdfReleaseCallback>,
+}
+
+impl Drop for WindowUdfCallbacks {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            debug!("Releasing host window UDF {}", self.user_data);
+            unsafe { release(self.user_data) };
+        }
+    }
+}
+
+/// Window UDF whose partitions are evaluated by the host.
+#[derive(Debug)]
+struct HostWindowUdf {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    callbacks: Arc<WindowUdfCallbacks>,
+}
+
+impl PartialEq for HostWindowUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.signature == other.signature
+            && self.return_type == other.return_type
+            && self.callbacks.user_data == other.callbacks.user_data
+    }
+}
+
+impl Eq for HostWindowUdf {}
+
+impl Hash for HostWindowUdf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+        self.return_type.hash(state);
+        self.callbacks.user_data.hash(state);
+    }
+}
+
+impl WindowUDFImpl for HostWindowUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn partition_evaluator(
+        &self,
+        _partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        Ok(Box::new(HostPartitionEvaluator {
+            name: self.name.clone(),
+            return_type: self.return_type.clone(),
+            callbacks: Arc::clone(&self.callbacks),
+        }))
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<FieldRef> {
+        Ok(Arc::new(Field::new(
+            field_args.name(),
+            self.return_type.clone(),
+            true,
+        )))
+    }
+}
+
+/// Partition evaluator delegating each window partition to the host.
+#[derive(Debug)]
+struct HostPartitionEvaluator {
+    name: String,
+    return_type: DataType,
+    callbacks: Arc<WindowUdfCallbacks>,
+}
+
+impl HostPartitionEvaluator {
+    fn import_result(&self, result_array: FFI_ArrowArray, num_rows: usize) -> Result<ArrayRef> {
+        let result = import_array(result_array, &self.return_type)?;
+        if result.len() != num_rows {
+            return Err(DataFusionError::Execution(format!(
+                "Host function '{}' returned {} values for {} rows",
+                self.name,
+                result.len(),
+                num_rows
+            )));
+        }
+        Ok(result)
+    }
+}
+
+impl PartitionEvaluator for HostPartitionEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> Result<ArrayRef> {
+        let Some(evaluate_all) = self.callbacks.evaluate_all else {
+            return Err(DataFusionError::Internal(format!(
+                "Host function '{}' does not implement evaluate_all",
+                self.name
+            )));
+        };
+
+        let (ffi_schema, ffi_array) = export_arrays(values, num_rows)?;
+        let mut result_array = FFI_ArrowArray::empty();
+        let code = unsafe {
+            evaluate_all(
+                self.callbacks.user_data,
+                &raw const ffi_schema,
+                &raw const ffi_array,
+                &raw mut result_array,
+            )
+        };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        self.import_result(result_array, num_rows)
+    }
+
+    fn evaluate_all_with_rank(
+        &self,
+        num_rows: usize,
+        ranks_in_partition: &[Range<usize>],
+    ) -> Result<ArrayRef> {
+        let Some(evaluate_all_with_rank) = self.callbacks.evaluate_all_with_rank else {
+            return Err(DataFusionError::Internal(format!(
+                "Host function '{}' does not implement evaluate_all_with_rank",
+                self.name
+            )));
+        };
+
+        let ranks = ranks_in_partition
+            .iter()
+            .map(|r| RankRange {
+                start: r.start as u64,
+                end: r.end as u64,
+            })
+            .collect::<Vec<_>>();
+        let mut result_array = FFI_ArrowArray::empty();
+        let code = unsafe {
+            evaluate_all_with_rank(
+                self.callbacks.user_data,
+                num_rows as u64,
+                ranks.as_ptr(),
+                ranks.len() as u64,
+                &raw mut result_array,
+            )
+        };
+        if code != ErrorCode::Ok {
+            return Err(host_error(&self.name, code));
+        }
+
+        self.import_result(result_array, num_rows)
+    }
+
+    fn include_rank(&self) -> bool {
+        self.callbacks.evaluate_all_with_rank.is_some()
+    }
+}
+
+/// Registers a window UDF whose partitions are evaluated by host callbacks.
+///
+/// The release callback is invoked when the function is dropped, including when registration fails.
+///
+/// This is a synchronous operation.
+/// The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `signature_bytes` must be a valid `BytesData` containing a protobuf-encoded `WindowUdfSignature`
+/// - `udwf_callbacks` must contain function pointers that are valid to call from any thread until release
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_window_udf(
+    context_ptr: *mut SessionContextWrapper,
+    signature_bytes: BytesData,
+    udwf_callbacks: WindowUdfCallbacks,
+    callback: Callback,
+    user_data: isize,
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let signature_proto = match proto::WindowUdfSignature::decode(signature_bytes.as_slice()) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Failed to decode window UDF signature protobuf: {e}");
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    debug!(
+        "Registering window UDF '{}' on session {context_ptr:p}",
+        signature_proto.name
+    );
+
+    let result = from_proto_window_udf(&signature_proto, udwf_callbacks)
+        .map(|udwf| {
+            context
+                .inner()
+                .register_udwf(WindowUDF::new_from_impl(udwf));
+        })
+        .map_err(|e| {
+            ErrorInfo::new(
+                ErrorCode::InvalidArgument,
+                format!("Invalid window UDF signature: {e}"),
+            )
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn from_proto_window_udf(
+    signature: &proto::WindowUdfSignature,
+    callbacks: WindowUdfCallbacks,
+) -> anyhow::Result<HostWindowUdf> {
+    if callbacks.evaluate_all.is_none() && callbacks.evaluate_all_with_rank.is_none() {
+        anyhow::bail!("Either evaluate_all or evaluate_all_with_rank callback is required");
+    }
+
+    let arg_types = signature
+        .arg_types
+        .iter()
+        .map(|t| mappers::from_proto_arrow_type(Some(t)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let return_type = mappers::from_proto_arrow_type(signature.return_type.as_ref())?;
+    let volatility = mappers::from_proto_volatility(signature.volatility)?;
+
+    Ok(HostWindowUdf {
+        name: signature.name.clone(),
+        signature: Signature::exact(arg_types, volatility),
+        return_type,
+        callbacks: Arc::new(callbacks),
+    })
+}
@@ -0,0 +1,17 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// A single configuration entry of a <see cref="SessionContext"/>.
+/// </summary>
+/// <param name="Key">Full name of the entry, e.g. "datafusion.execution.batch_size".</param>
+/// <param name="Value">Current value, or null if the entry has no value.</param>
+/// <param name="Description">Human-readable description of the entry.</param>
+public sealed record ConfigEntry(string Key, string? Value, string Description);
+
+internal static class ProtoConfigEntryExtensions
+{
+    internal static ConfigEntry ToConfigEntry(this Proto.ConfigEntry proto)
+    {
+        return new ConfigEntry(proto.Key, proto.HasValue ? proto.Value : null, proto.Description);
+    }
+}
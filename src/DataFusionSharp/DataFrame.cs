@@ -1,6 +1,7 @@
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
 using Apache.Arrow;
+using Apache.Arrow.Ipc;
 using DataFusionSharp.Formats;
 using DataFusionSharp.Formats.Csv;
 using DataFusionSharp.Formats.Json;
@@ -25,6 +26,8 @@ public sealed class DataFrame : IDisposable, ICloneable
     /// Gets the session context that created this DataFrame.
     /// </summary>
     public SessionContext Context { get; }
+
+    internal DataFrameSafeHandle Handle => _handle;
     
     internal DataFrame(SessionContext sessionContext, DataFrameSafeHandle handle)
     {
@@ -65,6 +68,360 @@ public sealed class DataFrame : IDisposable, ICloneable
         return this;
     }
 
+    /// <summary>
+    /// Creates a new DataFrame with only the given expressions as columns.
+    /// </summary>
+    /// <param name="expressions">The expressions to project.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression is invalid or cannot be resolved against the schema.</exception>
+    public DataFrame Select(IEnumerable<Proto.LogicalExprNode> expressions)
+    {
+        ArgumentNullException.ThrowIfNull(expressions);
+
+        using var exprsData = PinnedBytesData.FromMessage(new Proto.LogicalExprNodeCollection { LogicalExprNodes = { expressions } });
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameSelect(
+                _handle,
+                exprsData.ToBytesData(),
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start selecting DataFrame columns."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with only the rows for which the predicate evaluates to true.
+    /// </summary>
+    /// <param name="predicate">The boolean predicate expression.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the predicate is invalid or cannot be resolved against the schema.</exception>
+    public DataFrame Filter(Proto.LogicalExprNode predicate)
+    {
+        ArgumentNullException.ThrowIfNull(predicate);
+
+        using var predicateData = PinnedBytesData.FromMessage(predicate);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameFilter(
+                _handle,
+                predicateData.ToBytesData(),
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start filtering DataFrame."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame that groups rows by the group expressions and computes the aggregate expressions.
+    /// </summary>
+    /// <param name="groupExpressions">The expressions to group by. An empty collection aggregates all rows into a single row.</param>
+    /// <param name="aggregateExpressions">The aggregate expressions to compute for each group.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression is invalid or cannot be resolved against the schema.</exception>
+    public DataFrame Aggregate(IEnumerable<Proto.LogicalExprNode> groupExpressions, IEnumerable<Proto.LogicalExprNode> aggregateExpressions)
+    {
+        ArgumentNullException.ThrowIfNull(groupExpressions);
+        ArgumentNullException.ThrowIfNull(aggregateExpressions);
+
+        using var groupExprsData = PinnedBytesData.FromMessage(new Proto.LogicalExprNodeCollection { LogicalExprNodes = { groupExpressions } });
+        using var aggrExprsData = PinnedBytesData.FromMessage(new Proto.LogicalExprNodeCollection { LogicalExprNodes = { aggregateExpressions } });
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameAggregate(
+                _handle,
+                groupExprsData.ToBytesData(),
+                aggrExprsData.ToBytesData(),
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start aggregating DataFrame."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame sorted by the given sort expressions.
+    /// </summary>
+    /// <param name="sortExpressions">The sort expressions, in order of precedence.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression is invalid or cannot be resolved against the schema.</exception>
+    public DataFrame Sort(IEnumerable<Proto.SortExprNode> sortExpressions)
+    {
+        ArgumentNullException.ThrowIfNull(sortExpressions);
+
+        using var sortExprsData = PinnedBytesData.FromMessage(new Proto.SortExprNodeCollection { SortExprNodes = { sortExpressions } });
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameSort(
+                _handle,
+                sortExprsData.ToBytesData(),
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start sorting DataFrame."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame that skips <paramref name="skip"/> rows and returns at most <paramref name="fetch"/> rows.
+    /// </summary>
+    /// <param name="fetch">The maximum number of rows to return, or <see langword="null"/> for no limit.</param>
+    /// <param name="skip">The number of rows to skip.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public DataFrame Limit(ulong? fetch, ulong skip = 0)
+    {
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameLimit(
+                _handle,
+                skip,
+                fetch.HasValue ? (long)Math.Min(fetch.Value, long.MaxValue) : -1,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start limiting DataFrame."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with a column computed from an expression, replacing any existing column with the same name.
+    /// </summary>
+    /// <param name="name">The name of the column.</param>
+    /// <param name="expression">The expression computing the column values.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the expression is invalid or cannot be resolved against the schema.</exception>
+    public DataFrame WithColumn(string name, Proto.LogicalExprNode expression)
+    {
+        ArgumentNullException.ThrowIfNull(name);
+        ArgumentNullException.ThrowIfNull(expression);
+
+        using var exprData = PinnedBytesData.FromMessage(expression);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameWithColumn(
+                _handle,
+                name,
+                exprData.ToBytesData(),
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start adding DataFrame column."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with a column renamed. Renaming a column that does not exist is a no-op.
+    /// </summary>
+    /// <param name="oldName">The bare or qualified name of the column to rename.</param>
+    /// <param name="newName">The new name of the column.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public DataFrame WithColumnRenamed(string oldName, string newName)
+    {
+        ArgumentNullException.ThrowIfNull(oldName);
+        ArgumentNullException.ThrowIfNull(newName);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameWithColumnRenamed(
+                _handle,
+                oldName,
+                newName,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start renaming DataFrame column."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame without the given columns. Columns that do not exist are ignored.
+    /// </summary>
+    /// <param name="columnNames">The bare or qualified names of the columns to drop.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public DataFrame DropColumns(IEnumerable<string> columnNames)
+    {
+        ArgumentNullException.ThrowIfNull(columnNames);
+
+        var names = columnNames.ToArray();
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameDropColumns(
+                _handle,
+                names,
+                (uint)names.Length,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start dropping DataFrame columns."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame without duplicate rows.
+    /// </summary>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public DataFrame Distinct()
+    {
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameDistinct(
+                _handle,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start removing duplicate DataFrame rows."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame by joining this DataFrame with another one on equality of key columns.
+    /// </summary>
+    /// <param name="right">The right side of the join, created from the same <see cref="SessionContext"/>.</param>
+    /// <param name="joinType">The type of join.</param>
+    /// <param name="leftColumns">The key columns of this DataFrame.</param>
+    /// <param name="rightColumns">The key columns of <paramref name="right"/>, in the same order as <paramref name="leftColumns"/>.</param>
+    /// <param name="filter">An optional boolean expression applied to candidate row pairs in addition to the key equality. Ambiguous column names must be qualified.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the key columns or the filter cannot be resolved.</exception>
+    public DataFrame Join(
+        DataFrame right,
+        JoinType joinType,
+        IEnumerable<string> leftColumns,
+        IEnumerable<string> rightColumns,
+        Proto.LogicalExprNode? filter = null)
+    {
+        ArgumentNullException.ThrowIfNull(right);
+        ArgumentNullException.ThrowIfNull(leftColumns);
+        ArgumentNullException.ThrowIfNull(rightColumns);
+
+        var leftCols = leftColumns.ToArray();
+        var rightCols = rightColumns.ToArray();
+        using var filterData = PinnedBytesData.FromMessage(filter);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameJoin(
+                _handle,
+                right._handle,
+                (int)joinType.ToProto(),
+                leftCols,
+                (uint)leftCols.Length,
+                rightCols,
+                (uint)rightCols.Length,
+                filterData.ToBytesData(),
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start joining DataFrames."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with every combination of rows from this DataFrame and another one.
+    /// </summary>
+    /// <param name="right">The right side of the join, created from the same <see cref="SessionContext"/>.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public DataFrame CrossJoin(DataFrame right)
+    {
+        ArgumentNullException.ThrowIfNull(right);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameCrossJoin(
+                _handle,
+                right._handle,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start cross joining DataFrames."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with the rows of this DataFrame followed by the rows of another one with the same schema.
+    /// </summary>
+    /// <param name="other">The DataFrame to append.</param>
+    /// <param name="distinct">Whether to remove duplicate rows.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the schemas are not compatible.</exception>
+    public DataFrame Union(DataFrame other, bool distinct = false)
+    {
+        ArgumentNullException.ThrowIfNull(other);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameUnion(
+                _handle,
+                other._handle,
+                distinct,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start union of DataFrames."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with the rows of this DataFrame that are also in another one with the same schema.
+    /// </summary>
+    /// <param name="other">The DataFrame to intersect with.</param>
+    /// <param name="distinct">Whether to remove duplicate rows.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the schemas are not compatible.</exception>
+    public DataFrame Intersect(DataFrame other, bool distinct = false)
+    {
+        ArgumentNullException.ThrowIfNull(other);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameIntersect(
+                _handle,
+                other._handle,
+                distinct,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start intersect of DataFrames."));
+        }
+    }
+
+    /// <summary>
+    /// Creates a new DataFrame with the rows of this DataFrame that are not in another one with the same schema.
+    /// </summary>
+    /// <param name="other">The DataFrame with the rows to remove.</param>
+    /// <param name="distinct">Whether to remove duplicate rows.</param>
+    /// <returns>A new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the schemas are not compatible.</exception>
+    public DataFrame Except(DataFrame other, bool distinct = false)
+    {
+        ArgumentNullException.ThrowIfNull(other);
+
+        unsafe
+        {
+            var op = new SyncOperation<IntPtr>();
+            var result = NativeMethods.DataFrameExcept(
+                _handle,
+                other._handle,
+                distinct,
+                &GenericCallbacks.CallbackForHandleSync,
+                op.GetHandle());
+            return FromHandle(op.EnsureNativeCall(result, "Failed to start except of DataFrames."));
+        }
+    }
+
     /// <summary>
     /// Returns the number of rows in this DataFrame.
     /// </summary>
@@ -177,6 +534,75 @@ public sealed class DataFrame : IDisposable, ICloneable
         }
     }
     
+    /// <summary>
+    /// Executes the query and collects all results into memory, keeping the record batches grouped by output partition.
+    /// </summary>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the collected results.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<DataFramePartitionedCollectedResult> CollectPartitionedAsync(CancellationToken cancellationToken = default)
+    {
+        unsafe
+        {
+            var op = new AsyncOperation<DataFramePartitionedCollectedResult>(cancellationToken);
+            var result = NativeMethods.DataFrameCollectPartitioned(
+                _handle,
+                &CallbackForCollectPartitioned,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start collecting DataFrame partitions.");
+
+            return op.Task;
+        }
+    }
+
+    /// <summary>
+    /// Computes summary statistics of every column of this DataFrame.
+    /// </summary>
+    /// <remarks>
+    /// The result has a <c>describe</c> column with the statistic name and one column per input column,
+    /// <c>Float64</c> for numeric columns and <c>Utf8</c> otherwise. The statistics are <c>count</c>, <c>null_count</c>,
+    /// <c>mean</c>, <c>std</c>, <c>min</c>, <c>max</c> and <c>median</c>.
+    /// </remarks>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the collected statistics.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<DataFrameCollectedResult> DescribeAsync(CancellationToken cancellationToken = default) =>
+        DescribeAsync(false, [], cancellationToken);
+
+    /// <summary>
+    /// Computes the statistics of <see cref="DescribeAsync(CancellationToken)"/> plus approximate profile statistics of every column of this DataFrame.
+    /// </summary>
+    /// <remarks>
+    /// The result additionally contains <c>approx_distinct</c> for every column and <c>approx_percentile_&lt;q&gt;</c>
+    /// for every quantile, which is null for non-numeric columns.
+    /// </remarks>
+    /// <param name="quantiles">The quantiles between 0 and 1 to approximate, e.g. 0.5 for the median.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the collected statistics.</returns>
+    /// <exception cref="DataFusionException">Thrown when a quantile is out of range or the operation fails.</exception>
+    public Task<DataFrameCollectedResult> ProfileAsync(IEnumerable<double>? quantiles = null, CancellationToken cancellationToken = default) =>
+        DescribeAsync(true, quantiles?.ToArray() ?? [], cancellationToken);
+
+    private Task<DataFrameCollectedResult> DescribeAsync(bool profile, double[] quantiles, CancellationToken cancellationToken)
+    {
+        unsafe
+        {
+            var op = new AsyncOperation<DataFrameCollectedResult>(cancellationToken);
+            var result = NativeMethods.DataFrameDescribe(
+                _handle,
+                profile,
+                quantiles,
+                (uint)quantiles.Length,
+                &CallbackForCollect,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start describing DataFrame.");
+
+            return op.Task;
+        }
+    }
+
     /// <summary>
     /// Executes the query and returns a stream of record batches.
     /// </summary>
@@ -204,6 +630,220 @@ public sealed class DataFrame : IDisposable, ICloneable
         return new DataFrameStream(this, schema, streamHandle);
     }
 
+    /// <summary>
+    /// Executes the query and materializes its results in memory.
+    /// </summary>
+    /// <remarks>
+    /// The returned DataFrame scans the materialized batches instead of re-evaluating the original query.
+    /// </remarks>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing a new <see cref="DataFrame"/> over the materialized results.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<DataFrame> CacheAsync(CancellationToken cancellationToken = default)
+    {
+        Task<DataFrameSafeHandle> cacheTask;
+
+        unsafe
+        {
+            var op = new AsyncOperation<DataFrameSafeHandle>(cancellationToken);
+            var result = NativeMethods.DataFrameCache(
+                _handle,
+                &CallbackForClone,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start caching DataFrame.");
+            cacheTask = op.Task;
+        }
+
+        var dataFrameSafeHandle = await cacheTask.ConfigureAwait(false);
+
+        return new DataFrame(Context, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Explains the logical and physical plans of this DataFrame.
+    /// </summary>
+    /// <param name="format">How the plans are rendered.</param>
+    /// <param name="verbose">Whether to include the plan after every optimizer pass.</param>
+    /// <param name="analyze">Whether to execute the query and include execution metrics in the physical plan.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the explained plans. Text formats set <c>Text</c>, <see cref="ExplainFormat.Structured"/> sets <c>Root</c>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<Proto.ExplainResult> ExplainAsync(
+        ExplainFormat format = ExplainFormat.Indent,
+        bool verbose = false,
+        bool analyze = false,
+        CancellationToken cancellationToken = default)
+    {
+        Task<byte[]> explainTask;
+
+        unsafe
+        {
+            var op = new AsyncOperation<byte[]>(cancellationToken);
+            var result = NativeMethods.DataFrameExplain(
+                _handle,
+                verbose,
+                analyze,
+                (int)format.ToProto(),
+                &GenericCallbacks.CallbackForBytes,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start explaining DataFrame.");
+            explainTask = op.Task;
+        }
+
+        var bytes = await explainTask.ConfigureAwait(false);
+        return Proto.ExplainResult.Parser.ParseFrom(bytes);
+    }
+
+    /// <summary>
+    /// Explains the logical and physical plans of this DataFrame as the JSON mapping of structured operator trees.
+    /// </summary>
+    /// <param name="verbose">Whether to include the plan after every optimizer pass.</param>
+    /// <param name="analyze">Whether to execute the query and include execution metrics in the physical plan.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the explained plans as JSON.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<string> ExplainJsonAsync(bool verbose = false, bool analyze = false, CancellationToken cancellationToken = default)
+    {
+        unsafe
+        {
+            var op = new AsyncOperation<string>(cancellationToken);
+            var result = NativeMethods.DataFrameExplain(
+                _handle,
+                verbose,
+                analyze,
+                (int)Proto.ExplainFormat.Json,
+                &GenericCallbacks.CallbackForString,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start explaining DataFrame.");
+            return op.Task;
+        }
+    }
+
+    /// <summary>
+    /// Executes the query and returns one stream of record batches per output partition.
+    /// </summary>
+    /// <remarks>
+    /// The streams are independent and can be consumed concurrently. Every stream has to be disposed.
+    /// </remarks>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing one <see cref="DataFrameStream"/> per output partition.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<IReadOnlyList<DataFrameStream>> ExecuteStreamPartitionedAsync(CancellationToken cancellationToken = default)
+    {
+        Task<(Schema Schema, DataFrameStreamSafeHandle[] StreamHandles)> executeStreamsTask;
+
+        unsafe
+        {
+            var op = new AsyncOperation<(Schema Schema, DataFrameStreamSafeHandle[] StreamHandles)>(cancellationToken);
+            var result = NativeMethods.DataFrameExecuteStreamPartitioned(
+                _handle,
+                &CallbackForExecutedPartitionedStreams,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start executing partitioned streams on DataFrame.");
+            executeStreamsTask = op.Task;
+        }
+
+        var (schema, streamHandles) = await executeStreamsTask.ConfigureAwait(false);
+
+        return streamHandles.Select(h => new DataFrameStream(this, schema, h)).ToList().AsReadOnly();
+    }
+
+    /// <summary>
+    /// Executes the query and exports the results through the Arrow C stream interface.
+    /// </summary>
+    /// <remarks>
+    /// Batches are pulled directly from the native stream, and every read blocks the calling thread until the next batch is produced.
+    /// Dispose the stream to stop the execution early.
+    /// The stream keeps the <see cref="DataFusionRuntime"/> alive and has to be disposed before the runtime is disposed.
+    /// </remarks>
+    /// <param name="cancellationToken">Cancellation token to cancel starting the execution.</param>
+    /// <returns>A task containing the exported <see cref="IArrowArrayStream"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<IArrowArrayStream> ExecuteArrowStreamAsync(CancellationToken cancellationToken = default)
+    {
+        unsafe
+        {
+            var op = new AsyncOperation<IArrowArrayStream>(cancellationToken);
+            var result = NativeMethods.DataFrameExecuteArrowStream(
+                _handle,
+                &CallbackForArrowStream,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start executing Arrow stream on DataFrame.");
+
+            return op.Task;
+        }
+    }
+
+    /// <summary>
+    /// Returns the execution metrics of the physical plan run by the last completed <see cref="CollectAsync"/>.
+    /// </summary>
+    /// <returns>The metrics of the root operator and its inputs, or <see langword="null"/> if the DataFrame has not been collected.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Proto.OperatorMetrics? GetMetrics()
+    {
+        unsafe
+        {
+            var op = new SyncOperation<Proto.OperatorMetrics?>();
+            var result = NativeMethods.DataFrameMetrics(
+                _handle,
+                &CallbackForMetrics,
+                op.GetHandle());
+            return op.EnsureNativeCall(result, "Failed to get DataFrame execution metrics.");
+        }
+    }
+
+    /// <summary>
+    /// Serializes the logical plan of this DataFrame as a protobuf-encoded <c>datafusion.LogicalPlanNode</c>.
+    /// </summary>
+    /// <remarks>
+    /// Use <see cref="SessionContext.FromLogicalPlanAsync"/> to rebuild the DataFrame, possibly in another process.
+    /// Tables other than listing tables and views are encoded by name and must be registered under the same name in the session that imports the plan.
+    /// </remarks>
+    /// <returns>The serialized plan.</returns>
+    /// <exception cref="DataFusionException">Thrown when the plan cannot be serialized.</exception>
+    public byte[] ToLogicalPlanBytes()
+    {
+        unsafe
+        {
+            var op = new SyncOperation<byte[]>();
+            var result = NativeMethods.DataFrameLogicalPlanToBytes(
+                _handle,
+                &GenericCallbacks.CallbackForBytesSync,
+                op.GetHandle());
+            return op.EnsureNativeCall(result, "Failed to start serializing logical plan.");
+        }
+    }
+
+    /// <summary>
+    /// Creates the physical plan of this DataFrame and serializes it as a protobuf-encoded <c>datafusion.PhysicalPlanNode</c>.
+    /// </summary>
+    /// <remarks>
+    /// Use <see cref="SessionContext.ExecutePhysicalPlanPartitionAsync"/> to execute single partitions of the plan, possibly in other processes.
+    /// Scans of registered tables are encoded by name and must be registered under the same name, with the same partitioning, in the executing session.
+    /// </remarks>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the serialized plan and its number of output partitions.</returns>
+    /// <exception cref="DataFusionException">Thrown when the plan cannot be created or serialized.</exception>
+    public Task<SerializedPhysicalPlan> ToPhysicalPlanBytesAsync(CancellationToken cancellationToken = default)
+    {
+        unsafe
+        {
+            var op = new AsyncOperation<SerializedPhysicalPlan>(cancellationToken);
+            var result = NativeMethods.DataFramePhysicalPlanToBytes(
+                _handle,
+                &CallbackForPhysicalPlan,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start serializing physical plan.");
+            return op.Task;
+        }
+    }
+
     /// <summary>
     /// Writes the DataFrame contents to a CSV file.
     /// </summary>
@@ -329,6 +969,8 @@ public sealed class DataFrame : IDisposable, ICloneable
     }
     
     object ICloneable.Clone() => Clone();
+
+    private DataFrame FromHandle(IntPtr dataFrameHandle) => new(Context, new DataFrameSafeHandle(dataFrameHandle));
     
     /// <inheritdoc />
     public void Dispose()
@@ -480,7 +1122,7 @@ public sealed class DataFrame : IDisposable, ICloneable
     }
     
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
-    private static unsafe void CallbackForExecutedStream(IntPtr result, IntPtr error, IntPtr handle)
+    internal static unsafe void CallbackForExecutedStream(IntPtr result, IntPtr error, IntPtr handle)
     {
         var op = AsyncOperation<(Schema, DataFrameStreamSafeHandle)>.FromHandle(handle);
         
@@ -514,6 +1156,186 @@ public sealed class DataFrame : IDisposable, ICloneable
         else
             op.Complete(ValueTuple.Create(schema, streamSafeHandle));
     }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static unsafe void CallbackForCollectPartitioned(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = AsyncOperation<DataFramePartitionedCollectedResult>.FromHandle(handle);
+
+        if (error != IntPtr.Zero)
+        {
+            if (op is null)
+                return;
+
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        var data = (NativeDataFramePartitionedCollectedData*)result.ToPointer();
+        var partitions = new List<IReadOnlyList<RecordBatch>>(data->NumPartitions);
+        try
+        {
+            var schema = Apache.Arrow.C.CArrowSchemaImporter.ImportSchema(data->Schema);
+            for (var p = 0; p < data->NumPartitions; p++)
+            {
+                var partition = data->Partitions + p;
+                var batches = new List<RecordBatch>(partition->NumBatches);
+                partitions.Add(batches.AsReadOnly());
+                for (var i = 0; i < partition->NumBatches; i++)
+                    batches.Add(Apache.Arrow.C.CArrowArrayImporter.ImportRecordBatch(partition->Batches + i, schema));
+            }
+
+#pragma warning disable CA2000
+            var collectedResult = new DataFramePartitionedCollectedResult(partitions.AsReadOnly(), schema);
+#pragma warning restore CA2000
+            if (op is null)
+                collectedResult.Dispose(); // Nothing to complete, so dispose the collected result to avoid leaks.
+            else
+                op.Complete(collectedResult);
+        }
+        catch (Exception ex)
+        {
+            try
+            {
+                for (var p = 0; p < data->NumPartitions; p++)
+                {
+                    var partition = data->Partitions + p;
+                    for (var i = 0; i < partition->NumBatches; i++)
+                        Apache.Arrow.C.CArrowArray.CallReleaseFunc(partition->Batches + i);
+                }
+
+                foreach (var batch in partitions.SelectMany(b => b))
+                    batch.Dispose();
+            }
+            catch
+            {
+                // Ignore exceptions from release functions - we are already handling another exception and there's not much we can do about it.
+            }
+
+            op?.Complete(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static unsafe void CallbackForExecutedPartitionedStreams(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = AsyncOperation<(Schema, DataFrameStreamSafeHandle[])>.FromHandle(handle);
+
+        if (error != IntPtr.Zero)
+        {
+            if (op is null)
+                return;
+
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        var data = (NativeDataFrameExecutedPartitionedStreamData*)result.ToPointer();
+        var streamSafeHandles = new DataFrameStreamSafeHandle[data->NumStreams];
+#pragma warning disable CA2000
+        for (var i = 0; i < data->NumStreams; i++)
+            streamSafeHandles[i] = new DataFrameStreamSafeHandle(data->StreamHandles[i]);
+#pragma warning restore CA2000
+
+        Schema schema;
+        try
+        {
+            schema = Apache.Arrow.C.CArrowSchemaImporter.ImportSchema(data->Schema);
+        }
+        catch (Exception ex)
+        {
+            foreach (var streamSafeHandle in streamSafeHandles)
+                streamSafeHandle.Dispose();
+
+            op?.Complete(ex);
+            return;
+        }
+
+        if (op is null)
+        {
+            // Nothing to complete, so dispose the stream handles to avoid leaks.
+            foreach (var streamSafeHandle in streamSafeHandles)
+                streamSafeHandle.Dispose();
+        }
+        else
+        {
+            op.Complete(ValueTuple.Create(schema, streamSafeHandles));
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static unsafe void CallbackForArrowStream(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = AsyncOperation<IArrowArrayStream>.FromHandle(handle);
+        if (op is null)
+            return; // The native stream is released when the callback returns.
+
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        IArrowArrayStream stream;
+        try
+        {
+            // Importing moves the stream out of the native struct, so it outlives the callback.
+            stream = Apache.Arrow.C.CArrowArrayStreamImporter.ImportArrayStream((Apache.Arrow.C.CArrowArrayStream*)result.ToPointer());
+        }
+        catch (Exception ex)
+        {
+            op.Complete(ex);
+            return;
+        }
+
+        op.Complete(stream);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    internal static void CallbackForMetrics(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = SyncOperation<Proto.OperatorMetrics?>.FromHandle(handle);
+        if (op is null)
+            return;
+
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        if (result == IntPtr.Zero)
+        {
+            // Null result - the DataFrame has not been collected
+            op.Complete((Proto.OperatorMetrics?)null);
+            return;
+        }
+
+        var data = BytesData.FromIntPtr(result);
+        op.Complete(Proto.OperatorMetrics.Parser.ParseFrom(data.ToArray()));
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static unsafe void CallbackForPhysicalPlan(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = AsyncOperation<SerializedPhysicalPlan>.FromHandle(handle);
+        if (op is null)
+            return;
+
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        var data = (NativePhysicalPlanData*)result.ToPointer();
+        op.Complete(new SerializedPhysicalPlan(data->Plan.ToArray(), (int)data->PartitionCount));
+    }
 }
 
 /// <summary>
@@ -558,3 +1380,38 @@ public sealed class DataFrameCollectedResult : IDisposable
             batch.Dispose();
     }
 }
+
+/// <summary>
+/// Contains the collected Arrow arrays as batches grouped by output partition, and the schema from a DataFrame.
+/// Uses zero-copy Arrow import, so the data is not copied into .NET-owned memory -
+///   reference the memory allocated by native DataFusion runtime.
+/// </summary>
+/// <remarks>
+/// It is important to dispose of the <see cref="DataFramePartitionedCollectedResult"/> when it is no longer needed to free the native resources.
+/// Do not use the Arrow data after disposing, as it references memory owned by DataFusion that will be freed upon disposal.
+/// </remarks>
+public sealed class DataFramePartitionedCollectedResult : IDisposable
+{
+    /// <summary>
+    /// The collected record batches of every output partition.
+    /// </summary>
+    public IReadOnlyList<IReadOnlyList<RecordBatch>> Partitions { get; }
+
+    /// <summary>
+    /// The schema of the collected record batches.
+    /// </summary>
+    public Schema Schema { get; }
+
+    internal DataFramePartitionedCollectedResult(IReadOnlyList<IReadOnlyList<RecordBatch>> partitions, Schema schema)
+    {
+        Partitions = partitions;
+        Schema = schema;
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        foreach (var batch in Partitions.SelectMany(p => p))
+            batch.Dispose();
+    }
+}
@@ -30,16 +30,17 @@ public sealed class DataFrameStream : IAsyncEnumerable<RecordBatch>, IDisposable
     private readonly List<RecordBatch> _batches = [];
 
     /// <summary>
-    /// Gets the <see cref="DataFusionSharp.DataFrame"/> that created this stream.
+    /// Gets the <see cref="DataFusionSharp.DataFrame"/> that created this stream,
+    /// or <see langword="null"/> for streams executing a serialized physical plan.
     /// </summary>
-    public DataFrame DataFrame { get; }
+    public DataFrame? DataFrame { get; }
 
     /// <summary>
     /// Gets the <see cref="Apache.Arrow.Schema" /> of the record batches produced by this stream.
     /// </summary>
     public Schema Schema { get; }
     
-    internal DataFrameStream(DataFrame dataFrame, Schema schema, DataFrameStreamSafeHandle handle)
+    internal DataFrameStream(DataFrame? dataFrame, Schema schema, DataFrameStreamSafeHandle handle)
     {
         DataFrame = dataFrame;
         Schema = schema;
@@ -60,6 +61,28 @@ public sealed class DataFrameStream : IAsyncEnumerable<RecordBatch>, IDisposable
         }
     }
 
+    /// <summary>
+    /// Returns the execution metrics of the physical plan producing this stream.
+    /// </summary>
+    /// <remarks>
+    /// Metrics reflect the batches retrieved so far and are complete once the stream is drained.
+    /// </remarks>
+    /// <returns>The metrics of the root operator and its inputs.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Proto.OperatorMetrics GetMetrics()
+    {
+        unsafe
+        {
+            var op = new SyncOperation<Proto.OperatorMetrics?>();
+            var result = NativeMethods.DataFrameStreamMetrics(
+                _handle,
+                &DataFusionSharp.DataFrame.CallbackForMetrics,
+                op.GetHandle());
+            return op.EnsureNativeCall(result, "Failed to get stream execution metrics.")
+                ?? throw new InvalidOperationException("Stream execution metrics are missing.");
+        }
+    }
+
     /// <summary>
     /// Releases all resources used by this stream.
     /// </summary>
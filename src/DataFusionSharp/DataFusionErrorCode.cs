@@ -25,4 +25,10 @@ public enum DataFusionErrorCode
     ObjectStoreError = 8,
     /// <summary>The operation was cancelled before it could complete.</summary>
     Canceled = 9,
+    /// <summary>Query execution ran out of memory or disk space allowed by the runtime environment.</summary>
+    ResourcesExhausted = 10,
+    /// <summary>A catalog or schema operation failed.</summary>
+    CatalogError = 11,
+    /// <summary>A streaming table was pushed to after it was finished or no longer consumed.</summary>
+    StreamClosed = 12,
 }
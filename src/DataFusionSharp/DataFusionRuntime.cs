@@ -73,11 +73,16 @@ public sealed class DataFusionRuntime : IDisposable
     /// <summary>
     /// Creates a new session context for executing queries.
     /// </summary>
+    /// <param name="sessionConfig">Optional session configuration. If null, DataFusion defaults are used.</param>
+    /// <param name="runtimeEnvConfig">Optional runtime environment configuration. If null, memory is unbounded and spill files go to the OS temporary directory.</param>
     /// <returns>A new <see cref="SessionContext"/> instance.</returns>
-    /// <exception cref="DataFusionException">Thrown when context creation fails.</exception>
-    public SessionContext CreateSessionContext()
+    /// <exception cref="DataFusionException">Thrown when context creation fails, e.g. for an invalid configuration.</exception>
+    public SessionContext CreateSessionContext(SessionConfig? sessionConfig = null, RuntimeEnvConfig? runtimeEnvConfig = null)
     {
-        var errorCode = NativeMethods.ContextNew(_handle, out var contextHandle);
+        using var sessionConfigData = PinnedBytesData.FromMessage(sessionConfig?.ToProto());
+        using var runtimeEnvConfigData = PinnedBytesData.FromMessage(runtimeEnvConfig?.ToProto());
+
+        var errorCode = NativeMethods.ContextNew(_handle, sessionConfigData.ToBytesData(), runtimeEnvConfigData.ToBytesData(), out var contextHandle);
         DataFusionException.ThrowIfError(errorCode, "Failed to create DataFusion context");
         
         return new SessionContext(this, new SessionContextSafeHandle(contextHandle));
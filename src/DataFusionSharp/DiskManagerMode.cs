@@ -0,0 +1,33 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Where the disk manager creates temporary files when operators spill to disk.
+/// </summary>
+public enum DiskManagerMode
+{
+    /// <summary>
+    /// Temporary files are created in the OS temporary directory.
+    /// </summary>
+    OsTmpDirectory,
+
+    /// <summary>
+    /// Temporary files are created in the directories listed in <see cref="RuntimeEnvConfig.TempDirectories"/>.
+    /// </summary>
+    Directories,
+
+    /// <summary>
+    /// Spilling to disk is disabled; operators that run out of memory fail.
+    /// </summary>
+    Disabled
+}
+
+internal static class ProtoDiskManagerModeExtensions
+{
+    internal static Proto.DiskManagerMode ToProto(this DiskManagerMode mode) => mode switch
+    {
+        DiskManagerMode.OsTmpDirectory => Proto.DiskManagerMode.OsTmpDirectory,
+        DiskManagerMode.Directories => Proto.DiskManagerMode.Directories,
+        DiskManagerMode.Disabled => Proto.DiskManagerMode.Disabled,
+        _ => throw new ArgumentOutOfRangeException(nameof(mode), mode, "Invalid DiskManagerMode value")
+    };
+}
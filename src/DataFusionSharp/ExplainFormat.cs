@@ -0,0 +1,33 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// How <see cref="DataFrame.ExplainAsync"/> renders the plans of a DataFrame.
+/// </summary>
+public enum ExplainFormat
+{
+    /// <summary>
+    /// Plans are rendered as indented text, one operator per line.
+    /// </summary>
+    Indent,
+
+    /// <summary>
+    /// Plans are rendered as a tree of boxes connected by lines.
+    /// </summary>
+    Tree,
+
+    /// <summary>
+    /// Plans are returned as structured operator trees with properties and, when analyzed, execution metrics.
+    /// </summary>
+    Structured
+}
+
+internal static class ProtoExplainFormatExtensions
+{
+    internal static Proto.ExplainFormat ToProto(this ExplainFormat format) => format switch
+    {
+        ExplainFormat.Indent => Proto.ExplainFormat.Indent,
+        ExplainFormat.Tree => Proto.ExplainFormat.Tree,
+        ExplainFormat.Structured => Proto.ExplainFormat.Protobuf,
+        _ => throw new ArgumentOutOfRangeException(nameof(format), format, "Invalid ExplainFormat value")
+    };
+}
@@ -0,0 +1,39 @@
+using Apache.Arrow;
+
+namespace DataFusionSharp.Functions;
+
+/// <summary>
+/// Intermediate state of an <see cref="AggregateUdf"/> for one group.
+/// </summary>
+/// <remarks>
+/// An accumulator is only used by one thread at a time, but different accumulators of the same function may be used concurrently.
+/// Arrays passed to the methods are only valid during the call. Returned arrays are disposed once they are handed to DataFusion.
+/// </remarks>
+public abstract class Accumulator
+{
+    /// <summary>
+    /// Updates the accumulator with a batch of input rows.
+    /// </summary>
+    /// <param name="values">The argument columns, each with <paramref name="rowCount"/> values.</param>
+    /// <param name="rowCount">The number of rows in the batch.</param>
+    public abstract void Update(IReadOnlyList<IArrowArray> values, int rowCount);
+
+    /// <summary>
+    /// Merges partial states produced by <see cref="GetState"/> of other accumulators.
+    /// </summary>
+    /// <param name="states">The state columns, one per state field, each with <paramref name="rowCount"/> values.</param>
+    /// <param name="rowCount">The number of states in the batch.</param>
+    public abstract void Merge(IReadOnlyList<IArrowArray> states, int rowCount);
+
+    /// <summary>
+    /// Gets the intermediate state exchanged between partial and final aggregation.
+    /// </summary>
+    /// <returns>One single-value array per state field of the function, in declaration order.</returns>
+    public abstract IReadOnlyList<IArrowArray> GetState();
+
+    /// <summary>
+    /// Computes the aggregate result.
+    /// </summary>
+    /// <returns>An array with a single value of the function return type.</returns>
+    public abstract IArrowArray Evaluate();
+}
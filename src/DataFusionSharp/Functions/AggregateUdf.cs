@@ -0,0 +1,89 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Functions;
+
+/// <summary>
+/// Base class for aggregate user-defined functions implemented by the application.
+/// </summary>
+/// <remarks>
+/// Register an instance with <see cref="SessionContext.RegisterAggregateUdf"/> to call it from SQL and DataFrames.
+/// DataFusion creates one <see cref="Accumulator"/> per group and partition, possibly concurrently, so <see cref="CreateAccumulator"/> must be thread-safe.
+/// </remarks>
+public abstract class AggregateUdf
+{
+    /// <summary>
+    /// Initializes a new instance of the <see cref="AggregateUdf"/> class.
+    /// </summary>
+    /// <param name="name">Name used to call the function.</param>
+    /// <param name="argumentTypes">Exact argument types. Arguments are coerced to these types when possible.</param>
+    /// <param name="returnType">Type of the returned value.</param>
+    /// <param name="stateFields">Fields of the intermediate state exchanged between partial and final aggregation. At least one is required.</param>
+    /// <param name="volatility">Function volatility.</param>
+    protected AggregateUdf(
+        string name,
+        IReadOnlyList<IArrowType> argumentTypes,
+        IArrowType returnType,
+        IReadOnlyList<Field> stateFields,
+        Volatility volatility = Volatility.Volatile)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(argumentTypes);
+        ArgumentNullException.ThrowIfNull(returnType);
+        ArgumentNullException.ThrowIfNull(stateFields);
+
+        Name = name;
+        ArgumentTypes = argumentTypes;
+        ReturnType = returnType;
+        StateFields = stateFields;
+        Volatility = volatility;
+    }
+
+    /// <summary>
+    /// Gets the name used to call the function.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Gets the exact argument types of the function.
+    /// </summary>
+    public IReadOnlyList<IArrowType> ArgumentTypes { get; }
+
+    /// <summary>
+    /// Gets the type of the returned value.
+    /// </summary>
+    public IArrowType ReturnType { get; }
+
+    /// <summary>
+    /// Gets the fields of the intermediate accumulator state.
+    /// </summary>
+    public IReadOnlyList<Field> StateFields { get; }
+
+    /// <summary>
+    /// Gets the function volatility.
+    /// </summary>
+    public Volatility Volatility { get; }
+
+    /// <summary>
+    /// Creates an empty accumulator.
+    /// </summary>
+    /// <returns>A new accumulator.</returns>
+    public abstract Accumulator CreateAccumulator();
+}
+
+internal static class ProtoAggregateUdfExtensions
+{
+    internal static Proto.AggregateUdfSignature ToProto(this AggregateUdf udf)
+    {
+        var proto = new Proto.AggregateUdfSignature
+        {
+            Name = udf.Name,
+            ReturnType = udf.ReturnType.ToProto(),
+            Volatility = udf.Volatility.ToProto()
+        };
+        proto.ArgTypes.AddRange(udf.ArgumentTypes.Select(t => t.ToProto()));
+        proto.StateFields.AddRange(udf.StateFields.Select(ProtoArrowExtensions.FieldToProto));
+
+        return proto;
+    }
+}
@@ -0,0 +1,81 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Functions;
+
+/// <summary>
+/// Base class for scalar user-defined functions implemented by the application.
+/// </summary>
+/// <remarks>
+/// Register an instance with <see cref="SessionContext.RegisterScalarUdf"/> to call it from SQL and DataFrames.
+/// <see cref="Invoke"/> is called by the native library on its own threads, possibly concurrently, so implementations must be thread-safe.
+/// </remarks>
+public abstract class ScalarUdf
+{
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ScalarUdf"/> class.
+    /// </summary>
+    /// <param name="name">Name used to call the function.</param>
+    /// <param name="argumentTypes">Exact argument types. Arguments are coerced to these types when possible.</param>
+    /// <param name="returnType">Type of the returned values.</param>
+    /// <param name="volatility">Function volatility.</param>
+    protected ScalarUdf(string name, IReadOnlyList<IArrowType> argumentTypes, IArrowType returnType, Volatility volatility = Volatility.Volatile)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(argumentTypes);
+        ArgumentNullException.ThrowIfNull(returnType);
+
+        Name = name;
+        ArgumentTypes = argumentTypes;
+        ReturnType = returnType;
+        Volatility = volatility;
+    }
+
+    /// <summary>
+    /// Gets the name used to call the function.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Gets the exact argument types of the function.
+    /// </summary>
+    public IReadOnlyList<IArrowType> ArgumentTypes { get; }
+
+    /// <summary>
+    /// Gets the type of the returned values.
+    /// </summary>
+    public IArrowType ReturnType { get; }
+
+    /// <summary>
+    /// Gets the function volatility.
+    /// </summary>
+    public Volatility Volatility { get; }
+
+    /// <summary>
+    /// Evaluates the function over a batch of rows.
+    /// </summary>
+    /// <remarks>
+    /// The arguments are only valid during the call. The returned array is disposed once it is handed to DataFusion,
+    /// so it must be newly built and must not share buffers with the arguments.
+    /// </remarks>
+    /// <param name="arguments">The argument columns, each with <paramref name="rowCount"/> values.</param>
+    /// <param name="rowCount">The number of rows in the batch.</param>
+    /// <returns>An array of <see cref="ReturnType"/> with one value per row.</returns>
+    public abstract IArrowArray Invoke(IReadOnlyList<IArrowArray> arguments, int rowCount);
+}
+
+internal static class ProtoScalarUdfExtensions
+{
+    internal static Proto.ScalarUdfSignature ToProto(this ScalarUdf udf)
+    {
+        var proto = new Proto.ScalarUdfSignature
+        {
+            Name = udf.Name,
+            ReturnType = udf.ReturnType.ToProto(),
+            Volatility = udf.Volatility.ToProto()
+        };
+        proto.ArgTypes.AddRange(udf.ArgumentTypes.Select(t => t.ToProto()));
+
+        return proto;
+    }
+}
@@ -0,0 +1,37 @@
+using Apache.Arrow.Ipc;
+
+namespace DataFusionSharp.Functions;
+
+/// <summary>
+/// Base class for user-defined table functions implemented by the application, such as <c>SELECT * FROM my_function(1, 'a')</c>.
+/// </summary>
+/// <remarks>
+/// Register an instance with <see cref="SessionContext.RegisterTableFunction"/> to call it from SQL.
+/// <see cref="Call"/> is invoked while the query is planned, since the returned stream provides the result schema.
+/// The stream is read when the query first executes, possibly from another thread, and its batches are kept for later scans of the same call.
+/// </remarks>
+public abstract class TableFunction
+{
+    /// <summary>
+    /// Initializes a new instance of the <see cref="TableFunction"/> class.
+    /// </summary>
+    /// <param name="name">Name used to call the function.</param>
+    protected TableFunction(string name)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+
+        Name = name;
+    }
+
+    /// <summary>
+    /// Gets the name used to call the function.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Evaluates a call of the function.
+    /// </summary>
+    /// <param name="arguments">The call arguments in order. Only constant arguments are accepted, and they are folded to literals first.</param>
+    /// <returns>A stream with the result schema and batches. It is disposed once it has been read.</returns>
+    public abstract IArrowArrayStream Call(IReadOnlyList<Proto.ScalarValueAndMetadata> arguments);
+}
@@ -0,0 +1,33 @@
+namespace DataFusionSharp.Functions;
+
+/// <summary>
+/// Volatility of a user-defined function, used by the optimizer to decide when it may be evaluated.
+/// </summary>
+public enum Volatility
+{
+    /// <summary>
+    /// May return a different output on every call, e.g. <c>random()</c>. The function is never evaluated during planning.
+    /// </summary>
+    Volatile,
+
+    /// <summary>
+    /// Always returns the same output for the same input, so it can be evaluated during planning.
+    /// </summary>
+    Immutable,
+
+    /// <summary>
+    /// Returns the same output for the same input within a single query, e.g. <c>now()</c>.
+    /// </summary>
+    Stable
+}
+
+internal static class ProtoVolatilityExtensions
+{
+    internal static Proto.Volatility ToProto(this Volatility volatility) => volatility switch
+    {
+        Volatility.Volatile => Proto.Volatility.Volatile,
+        Volatility.Immutable => Proto.Volatility.Immutable,
+        Volatility.Stable => Proto.Volatility.Stable,
+        _ => throw new ArgumentOutOfRangeException(nameof(volatility), volatility, "Invalid Volatility value")
+    };
+}
@@ -0,0 +1,104 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Functions;
+
+/// <summary>
+/// Base class for window user-defined functions implemented by the application.
+/// </summary>
+/// <remarks>
+/// Register an instance with <see cref="SessionContext.RegisterWindowUdf"/> to call it with an <c>OVER</c> clause.
+/// Each window partition is evaluated at once, either from its argument columns with <see cref="Evaluate"/>
+/// or, when <see cref="IncludeRank"/> is <see langword="true"/>, from the row ranks with <see cref="EvaluateWithRank"/>.
+/// Partitions may be evaluated concurrently, so implementations must be thread-safe.
+/// </remarks>
+public abstract class WindowUdf
+{
+    /// <summary>
+    /// Initializes a new instance of the <see cref="WindowUdf"/> class.
+    /// </summary>
+    /// <param name="name">Name used to call the function.</param>
+    /// <param name="argumentTypes">Exact argument types. Arguments are coerced to these types when possible.</param>
+    /// <param name="returnType">Type of the returned values.</param>
+    /// <param name="volatility">Function volatility.</param>
+    protected WindowUdf(string name, IReadOnlyList<IArrowType> argumentTypes, IArrowType returnType, Volatility volatility = Volatility.Volatile)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(argumentTypes);
+        ArgumentNullException.ThrowIfNull(returnType);
+
+        Name = name;
+        ArgumentTypes = argumentTypes;
+        ReturnType = returnType;
+        Volatility = volatility;
+    }
+
+    /// <summary>
+    /// Gets the name used to call the function.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Gets the exact argument types of the function.
+    /// </summary>
+    public IReadOnlyList<IArrowType> ArgumentTypes { get; }
+
+    /// <summary>
+    /// Gets the type of the returned values.
+    /// </summary>
+    public IArrowType ReturnType { get; }
+
+    /// <summary>
+    /// Gets the function volatility.
+    /// </summary>
+    public Volatility Volatility { get; }
+
+    /// <summary>
+    /// Gets a value indicating whether partitions are evaluated from row ranks with <see cref="EvaluateWithRank"/> instead of <see cref="Evaluate"/>.
+    /// </summary>
+    public virtual bool IncludeRank => false;
+
+    /// <summary>
+    /// Evaluates the function over a whole window partition.
+    /// </summary>
+    /// <remarks>
+    /// The arguments are only valid during the call. The returned array is disposed once it is handed to DataFusion.
+    /// </remarks>
+    /// <param name="arguments">The argument columns in the partition's <c>ORDER BY</c> order, each with <paramref name="rowCount"/> values.</param>
+    /// <param name="rowCount">The number of rows in the partition.</param>
+    /// <returns>An array of <see cref="ReturnType"/> with one value per row.</returns>
+    public virtual IArrowArray Evaluate(IReadOnlyList<IArrowArray> arguments, int rowCount)
+    {
+        throw new NotSupportedException($"Window function '{Name}' does not implement {nameof(Evaluate)}.");
+    }
+
+    /// <summary>
+    /// Evaluates the function over a whole window partition using only the row ranks.
+    /// </summary>
+    /// <remarks>
+    /// Only called when <see cref="IncludeRank"/> is <see langword="true"/>. The returned array is disposed once it is handed to DataFusion.
+    /// </remarks>
+    /// <param name="rowCount">The number of rows in the partition.</param>
+    /// <param name="ranks">Ranges of rows that are peers under the <c>ORDER BY</c> clause, in order.</param>
+    /// <returns>An array of <see cref="ReturnType"/> with one value per row.</returns>
+    public virtual IArrowArray EvaluateWithRank(int rowCount, IReadOnlyList<Range> ranks)
+    {
+        throw new NotSupportedException($"Window function '{Name}' does not implement {nameof(EvaluateWithRank)}.");
+    }
+}
+
+internal static class ProtoWindowUdfExtensions
+{
+    internal static Proto.WindowUdfSignature ToProto(this WindowUdf udf)
+    {
+        var proto = new Proto.WindowUdfSignature
+        {
+            Name = udf.Name,
+            ReturnType = udf.ReturnType.ToProto(),
+            Volatility = udf.Volatility.ToProto()
+        };
+        proto.ArgTypes.AddRange(udf.ArgumentTypes.Select(t => t.ToProto()));
+
+        return proto;
+    }
+}
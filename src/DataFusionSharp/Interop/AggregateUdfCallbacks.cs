@@ -0,0 +1,118 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using Apache.Arrow;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Native callbacks forwarding to a host <see cref="AggregateUdf"/> and its accumulators.
+/// </summary>
+internal static unsafe class AggregateUdfCallbacks
+{
+    internal static NativeAggregateUdfCallbacks Create(AggregateUdf udf)
+    {
+        return new NativeAggregateUdfCallbacks
+        {
+            UserData = HostObjectHandle.Alloc(udf),
+            CreateAccumulator = &CreateAccumulator,
+            UpdateBatch = &UpdateBatch,
+            MergeBatch = &MergeBatch,
+            State = &State,
+            Evaluate = &Evaluate,
+            ReleaseAccumulator = &HostObjectHandle.Release,
+            Release = &HostObjectHandle.Release
+        };
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode CreateAccumulator(IntPtr userData, IntPtr* accumulatorOut)
+    {
+        try
+        {
+            var udf = HostObjectHandle.Get<AggregateUdf>(userData);
+            *accumulatorOut = HostObjectHandle.Alloc(new AccumulatorEntry(udf, udf.CreateAccumulator()));
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode UpdateBatch(IntPtr accumulatorHandle, Apache.Arrow.C.CArrowSchema* schema, Apache.Arrow.C.CArrowArray* array)
+    {
+        try
+        {
+            var accumulator = HostObjectHandle.Get<AccumulatorEntry>(accumulatorHandle).Accumulator;
+
+            using var values = ScalarUdfCallbacks.ImportArguments(schema, array);
+            accumulator.Update([.. values.Arrays], values.Length);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode MergeBatch(IntPtr accumulatorHandle, Apache.Arrow.C.CArrowSchema* schema, Apache.Arrow.C.CArrowArray* array)
+    {
+        try
+        {
+            var accumulator = HostObjectHandle.Get<AccumulatorEntry>(accumulatorHandle).Accumulator;
+
+            using var states = ScalarUdfCallbacks.ImportArguments(schema, array);
+            accumulator.Merge([.. states.Arrays], states.Length);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode State(IntPtr accumulatorHandle, Apache.Arrow.C.CArrowArray* stateOut)
+    {
+        try
+        {
+            var (udf, accumulator) = HostObjectHandle.Get<AccumulatorEntry>(accumulatorHandle);
+
+            // The state is exported as a struct array with one row; the native library reads it as the declared state fields
+            using var state = new RecordBatch(new Schema(udf.StateFields, null), accumulator.GetState(), 1);
+            for (var i = 0; i < udf.StateFields.Count; i++)
+            {
+                if (state.Column(i).Data.DataType.TypeId != udf.StateFields[i].DataType.TypeId)
+                    throw new InvalidOperationException($"State field '{udf.StateFields[i].Name}' has type {state.Column(i).Data.DataType.Name}, but {udf.StateFields[i].DataType.Name} was declared.");
+            }
+            Apache.Arrow.C.CArrowArrayExporter.ExportRecordBatch(state, stateOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode Evaluate(IntPtr accumulatorHandle, Apache.Arrow.C.CArrowArray* resultOut)
+    {
+        try
+        {
+            var (udf, accumulator) = HostObjectHandle.Get<AccumulatorEntry>(accumulatorHandle);
+
+            using var result = accumulator.Evaluate();
+            ScalarUdfCallbacks.ExportResult(result, udf.ReturnType, resultOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    private sealed record AccumulatorEntry(AggregateUdf Udf, Accumulator Accumulator);
+}
@@ -58,4 +58,57 @@ internal static class GenericCallbacks
         var dataBytes = data.ToArray();
         op.Complete(dataBytes);
     }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    internal static void CallbackForBytesSync(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = SyncOperation<byte[]>.FromHandle(handle);
+        if (op is null)
+            return;
+
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        var data = BytesData.FromIntPtr(result);
+        var dataBytes = data.ToArray();
+        op.Complete(dataBytes);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    internal static void CallbackForBoolSync(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = SyncOperation<bool>.FromHandle(handle);
+        if (op is null)
+            return;
+
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        op.Complete(Marshal.ReadByte(result) != 0);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    internal static void CallbackForHandleSync(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = SyncOperation<IntPtr>.FromHandle(handle);
+        if (op is null)
+            return;
+
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        op.Complete(Marshal.ReadIntPtr(result));
+    }
 }
\ No newline at end of file
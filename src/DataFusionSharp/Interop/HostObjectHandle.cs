@@ -0,0 +1,37 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Passes host implemented objects, such as table providers and UDFs, to the native library as callback user data.
+/// </summary>
+internal static class HostObjectHandle
+{
+    internal static IntPtr Alloc(object target)
+    {
+        return GCHandle.ToIntPtr(GCHandle.Alloc(target, GCHandleType.Normal));
+    }
+
+    internal static T Get<T>(IntPtr userData)
+        where T : class
+    {
+        return (T)GCHandle.FromIntPtr(userData).Target!;
+    }
+
+    /// <summary>
+    /// Maps an exception thrown by host code to the error code returned to the native library.
+    /// </summary>
+    internal static DataFusionErrorCode ToErrorCode(Exception exception)
+    {
+        return exception is DataFusionException { ErrorCode: not DataFusionErrorCode.Ok } dataFusionException
+            ? dataFusionException.ErrorCode
+            : DataFusionErrorCode.Panic;
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    internal static void Release(IntPtr userData)
+    {
+        GCHandle.FromIntPtr(userData).Free();
+    }
+}
@@ -1,4 +1,5 @@
 using System.Runtime.InteropServices;
+using System.Runtime.InteropServices.Marshalling;
 
 namespace DataFusionSharp.Interop;
 
@@ -61,7 +62,7 @@ internal static unsafe partial class NativeMethods
     // Context
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_new")]
-    public static partial DataFusionErrorCode ContextNew(RuntimeSafeHandle runtimeHandle, out IntPtr contextHandle);
+    public static partial DataFusionErrorCode ContextNew(RuntimeSafeHandle runtimeHandle, BytesData sessionConfigData, BytesData runtimeEnvConfigData, out IntPtr contextHandle);
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_destroy")]
     public static partial DataFusionErrorCode ContextDestroy(IntPtr contextHandle);
@@ -107,6 +108,34 @@ internal static unsafe partial class NativeMethods
         Callback callback,
         IntPtr userData);
 
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_batches")]
+    public static partial DataFusionErrorCode ContextRegisterBatches(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        BytesData batchesIpcData,
+        uint partitionCount,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_ffi_batches")]
+    public static partial DataFusionErrorCode ContextRegisterFfiBatches(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        Apache.Arrow.C.CArrowSchema* schema,
+        Apache.Arrow.C.CArrowArray* arrays,
+        uint arraysLength,
+        uint partitionCount,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_table_provider")]
+    public static partial DataFusionErrorCode ContextRegisterTableProvider(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        NativeTableProviderCallbacks providerCallbacks,
+        Callback callback,
+        IntPtr userData);
+
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_deregister_table")]
     public static partial DataFusionErrorCode ContextDeregisterTable(
         SessionContextSafeHandle contextHandle,
@@ -114,6 +143,36 @@ internal static unsafe partial class NativeMethods
         Callback callback,
         IntPtr userData);
 
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_csv")]
+    public static partial DataFusionErrorCode ContextReadCsv(
+        SessionContextSafeHandle contextHandle,
+        [MarshalUsing(typeof(Utf8StringMarshaller), ElementIndirectionDepth = 1)] string[] filePaths,
+        uint filePathsLength,
+        BytesData optionsData,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_json")]
+    public static partial DataFusionErrorCode ContextReadJson(
+        SessionContextSafeHandle contextHandle,
+        [MarshalUsing(typeof(Utf8StringMarshaller), ElementIndirectionDepth = 1)] string[] filePaths,
+        uint filePathsLength,
+        BytesData optionsData,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_parquet")]
+    public static partial DataFusionErrorCode ContextReadParquet(
+        SessionContextSafeHandle contextHandle,
+        [MarshalUsing(typeof(Utf8StringMarshaller), ElementIndirectionDepth = 1)] string[] filePaths,
+        uint filePathsLength,
+        BytesData optionsData,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_sql")]
     public static partial DataFusionErrorCode ContextSql(
         SessionContextSafeHandle contextHandle,
@@ -123,6 +182,106 @@ internal static unsafe partial class NativeMethods
         IntPtr userData,
         out IntPtr cancellationTokenHandle);
 
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_table")]
+    public static partial DataFusionErrorCode ContextTable(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_set_option")]
+    public static partial DataFusionErrorCode ContextSetOption(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string key,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string value,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_get_option")]
+    public static partial DataFusionErrorCode ContextGetOption(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string key,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_config_entries")]
+    public static partial DataFusionErrorCode ContextConfigEntries(
+        SessionContextSafeHandle contextHandle,
+        Callback callback,
+        IntPtr userData);
+
+    // Catalog
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_create_catalog")]
+    public static partial DataFusionErrorCode ContextCreateCatalog(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_drop_catalog")]
+    public static partial DataFusionErrorCode ContextDropCatalog(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName,
+        [MarshalAs(UnmanagedType.I1)] bool cascade,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_catalog_names")]
+    public static partial DataFusionErrorCode ContextCatalogNames(
+        SessionContextSafeHandle contextHandle,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_create_schema")]
+    public static partial DataFusionErrorCode ContextCreateSchema(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string schemaName,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_drop_schema")]
+    public static partial DataFusionErrorCode ContextDropSchema(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string schemaName,
+        [MarshalAs(UnmanagedType.I1)] bool cascade,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_schema_names")]
+    public static partial DataFusionErrorCode ContextSchemaNames(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_table_names")]
+    public static partial DataFusionErrorCode ContextTableNames(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string schemaName,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_table_exist")]
+    public static partial DataFusionErrorCode ContextTableExist(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_table_schema")]
+    public static partial DataFusionErrorCode ContextTableSchema(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
     // Object Store
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_object_store_local")]
@@ -222,6 +381,13 @@ internal static unsafe partial class NativeMethods
         IntPtr userData,
         out IntPtr cancellationTokenHandle);
 
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_collect_partitioned")]
+    public static partial DataFusionErrorCode DataFrameCollectPartitioned(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_to_string")]
     public static partial DataFusionErrorCode DataFrameToString(
         DataFrameSafeHandle dataFrameHandle,
@@ -268,6 +434,20 @@ internal static unsafe partial class NativeMethods
         IntPtr userData,
         out IntPtr cancellationTokenHandle);
 
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_execute_stream_partitioned")]
+    public static partial DataFusionErrorCode DataFrameExecuteStreamPartitioned(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_execute_arrow_stream")]
+    public static partial DataFusionErrorCode DataFrameExecuteArrowStream(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_stream_destroy")]
     public static partial DataFusionErrorCode DataFrameStreamDestroy(IntPtr streamHandle);
 
@@ -313,4 +493,264 @@ internal static unsafe partial class NativeMethods
         Callback callback,
         IntPtr userData,
         out IntPtr cancellationTokenHandle);
-}
+
+    // Streaming Table
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_streaming_table")]
+    public static partial DataFusionErrorCode ContextRegisterStreamingTable(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName,
+        Apache.Arrow.C.CArrowSchema* schema,
+        uint capacity,
+        [MarshalAs(UnmanagedType.I1)] bool unbounded,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_streaming_table_destroy")]
+    public static partial DataFusionErrorCode StreamingTableDestroy(IntPtr tableHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_streaming_table_push_batch")]
+    public static partial DataFusionErrorCode StreamingTablePushBatch(
+        StreamingTableSafeHandle tableHandle,
+        BytesData batchesIpcData,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_streaming_table_finish")]
+    public static partial DataFusionErrorCode StreamingTableFinish(StreamingTableSafeHandle tableHandle);
+
+    // Functions
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_scalar_udf")]
+    public static partial DataFusionErrorCode ContextRegisterScalarUdf(
+        SessionContextSafeHandle contextHandle,
+        BytesData signature,
+        NativeScalarUdfCallbacks udfCallbacks,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_aggregate_udf")]
+    public static partial DataFusionErrorCode ContextRegisterAggregateUdf(
+        SessionContextSafeHandle contextHandle,
+        BytesData signature,
+        NativeAggregateUdfCallbacks udafCallbacks,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_window_udf")]
+    public static partial DataFusionErrorCode ContextRegisterWindowUdf(
+        SessionContextSafeHandle contextHandle,
+        BytesData signature,
+        NativeWindowUdfCallbacks udwfCallbacks,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_table_function")]
+    public static partial DataFusionErrorCode ContextRegisterTableFunction(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string name,
+        NativeTableFunctionCallbacks udtfCallbacks,
+        Callback callback,
+        IntPtr userData);
+
+    // Plan
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_logical_plan_to_bytes")]
+    public static partial DataFusionErrorCode DataFrameLogicalPlanToBytes(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_dataframe_from_logical_plan")]
+    public static partial DataFusionErrorCode ContextDataFrameFromLogicalPlan(
+        SessionContextSafeHandle contextHandle,
+        BytesData plan,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_physical_plan_to_bytes")]
+    public static partial DataFusionErrorCode DataFramePhysicalPlanToBytes(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_execute_physical_plan_partition")]
+    public static partial DataFusionErrorCode ContextExecutePhysicalPlanPartition(
+        SessionContextSafeHandle contextHandle,
+        BytesData plan,
+        uint partition,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    // Explain
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_explain")]
+    public static partial DataFusionErrorCode DataFrameExplain(
+        DataFrameSafeHandle dataFrameHandle,
+        [MarshalAs(UnmanagedType.I1)] bool verbose,
+        [MarshalAs(UnmanagedType.I1)] bool analyze,
+        int format,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    // Metrics
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_metrics")]
+    public static partial DataFusionErrorCode DataFrameMetrics(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_stream_metrics")]
+    public static partial DataFusionErrorCode DataFrameStreamMetrics(
+        DataFrameStreamSafeHandle streamHandle,
+        Callback callback,
+        IntPtr userData);
+
+    // Transformations
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_select")]
+    public static partial DataFusionErrorCode DataFrameSelect(
+        DataFrameSafeHandle dataFrameHandle,
+        BytesData exprsData,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_filter")]
+    public static partial DataFusionErrorCode DataFrameFilter(
+        DataFrameSafeHandle dataFrameHandle,
+        BytesData predicateData,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_aggregate")]
+    public static partial DataFusionErrorCode DataFrameAggregate(
+        DataFrameSafeHandle dataFrameHandle,
+        BytesData groupExprsData,
+        BytesData aggrExprsData,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_sort")]
+    public static partial DataFusionErrorCode DataFrameSort(
+        DataFrameSafeHandle dataFrameHandle,
+        BytesData sortExprsData,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_limit")]
+    public static partial DataFusionErrorCode DataFrameLimit(
+        DataFrameSafeHandle dataFrameHandle,
+        ulong skip,
+        long fetch,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_with_column")]
+    public static partial DataFusionErrorCode DataFrameWithColumn(
+        DataFrameSafeHandle dataFrameHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string name,
+        BytesData exprData,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_with_column_renamed")]
+    public static partial DataFusionErrorCode DataFrameWithColumnRenamed(
+        DataFrameSafeHandle dataFrameHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string oldName,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string newName,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_drop_columns")]
+    public static partial DataFusionErrorCode DataFrameDropColumns(
+        DataFrameSafeHandle dataFrameHandle,
+        [MarshalUsing(typeof(Utf8StringMarshaller), ElementIndirectionDepth = 1)] string[] names,
+        uint namesLength,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_distinct")]
+    public static partial DataFusionErrorCode DataFrameDistinct(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_join")]
+    public static partial DataFusionErrorCode DataFrameJoin(
+        DataFrameSafeHandle leftHandle,
+        DataFrameSafeHandle rightHandle,
+        int joinType,
+        [MarshalUsing(typeof(Utf8StringMarshaller), ElementIndirectionDepth = 1)] string[] leftColumns,
+        uint leftColumnsLength,
+        [MarshalUsing(typeof(Utf8StringMarshaller), ElementIndirectionDepth = 1)] string[] rightColumns,
+        uint rightColumnsLength,
+        BytesData filterData,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_cross_join")]
+    public static partial DataFusionErrorCode DataFrameCrossJoin(
+        DataFrameSafeHandle leftHandle,
+        DataFrameSafeHandle rightHandle,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_union")]
+    public static partial DataFusionErrorCode DataFrameUnion(
+        DataFrameSafeHandle leftHandle,
+        DataFrameSafeHandle rightHandle,
+        [MarshalAs(UnmanagedType.I1)] bool distinct,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_intersect")]
+    public static partial DataFusionErrorCode DataFrameIntersect(
+        DataFrameSafeHandle leftHandle,
+        DataFrameSafeHandle rightHandle,
+        [MarshalAs(UnmanagedType.I1)] bool distinct,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_except")]
+    public static partial DataFusionErrorCode DataFrameExcept(
+        DataFrameSafeHandle leftHandle,
+        DataFrameSafeHandle rightHandle,
+        [MarshalAs(UnmanagedType.I1)] bool distinct,
+        Callback callback,
+        IntPtr userData);
+
+    // Views
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_view")]
+    public static partial DataFusionErrorCode ContextRegisterView(
+        SessionContextSafeHandle contextHandle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string viewName,
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_cache")]
+    public static partial DataFusionErrorCode DataFrameCache(
+        DataFrameSafeHandle dataFrameHandle,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+
+    // Describe
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_describe")]
+    public static partial DataFusionErrorCode DataFrameDescribe(
+        DataFrameSafeHandle dataFrameHandle,
+        [MarshalAs(UnmanagedType.I1)] bool profile,
+        double[] quantiles,
+        uint quantilesLength,
+        Callback callback,
+        IntPtr userData,
+        out IntPtr cancellationTokenHandle);
+}
\ No newline at end of file
@@ -15,4 +15,96 @@ internal unsafe struct NativeDataFrameExecutedStreamData
 {
     public IntPtr StreamHandle;
     public Apache.Arrow.C.CArrowSchema* Schema;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeCollectedPartition
+{
+    public int NumBatches;
+    public Apache.Arrow.C.CArrowArray* Batches;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeDataFramePartitionedCollectedData
+{
+    public Apache.Arrow.C.CArrowSchema* Schema;
+    public int NumPartitions;
+    public NativeCollectedPartition* Partitions;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeDataFrameExecutedPartitionedStreamData
+{
+    public Apache.Arrow.C.CArrowSchema* Schema;
+    public int NumStreams;
+    public IntPtr* StreamHandles;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeTableSchemaData
+{
+    public Apache.Arrow.C.CArrowSchema* Schema;
+    public int TableKind;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeTableProviderCallbacks
+{
+    public IntPtr UserData;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowSchema*, DataFusionErrorCode> Schema;
+    public delegate* unmanaged[Cdecl]<IntPtr, uint*, uint, BytesData, long, Apache.Arrow.C.CArrowArrayStream*, DataFusionErrorCode> Scan;
+    public delegate* unmanaged[Cdecl]<IntPtr, BytesData, int*, uint, DataFusionErrorCode> SupportsFiltersPushdown;
+    public delegate* unmanaged[Cdecl]<IntPtr, void> Release;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeScalarUdfCallbacks
+{
+    public IntPtr UserData;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowSchema*, Apache.Arrow.C.CArrowArray*, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> Invoke;
+    public delegate* unmanaged[Cdecl]<IntPtr, void> Release;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeAggregateUdfCallbacks
+{
+    public IntPtr UserData;
+    public delegate* unmanaged[Cdecl]<IntPtr, IntPtr*, DataFusionErrorCode> CreateAccumulator;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowSchema*, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> UpdateBatch;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowSchema*, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> MergeBatch;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> State;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> Evaluate;
+    public delegate* unmanaged[Cdecl]<IntPtr, void> ReleaseAccumulator;
+    public delegate* unmanaged[Cdecl]<IntPtr, void> Release;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal struct NativeRankRange
+{
+    public ulong Start;
+    public ulong End;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeWindowUdfCallbacks
+{
+    public IntPtr UserData;
+    public delegate* unmanaged[Cdecl]<IntPtr, Apache.Arrow.C.CArrowSchema*, Apache.Arrow.C.CArrowArray*, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> EvaluateAll;
+    public delegate* unmanaged[Cdecl]<IntPtr, ulong, NativeRankRange*, ulong, Apache.Arrow.C.CArrowArray*, DataFusionErrorCode> EvaluateAllWithRank;
+    public delegate* unmanaged[Cdecl]<IntPtr, void> Release;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct NativeTableFunctionCallbacks
+{
+    public IntPtr UserData;
+    public delegate* unmanaged[Cdecl]<IntPtr, BytesData, Apache.Arrow.C.CArrowArrayStream*, DataFusionErrorCode> Call;
+    public delegate* unmanaged[Cdecl]<IntPtr, void> Release;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal struct NativePhysicalPlanData
+{
+    public BytesData Plan;
+    public uint PartitionCount;
 }
\ No newline at end of file
@@ -159,3 +159,28 @@ internal sealed class InMemoryStoreSafeHandle : DataFusionSafeHandle
         return NativeMethods.InMemoryStoreDestroy(handle) == DataFusionErrorCode.Ok;
     }
 }
+
+internal sealed class StreamingTableSafeHandle : DataFusionSafeHandle
+{
+#if MEMORY_TEST
+    private static long _liveInstances;
+    internal static long LiveInstances => Interlocked.Read(ref _liveInstances);
+#endif
+
+    internal StreamingTableSafeHandle(IntPtr handle)
+        : base(handle)
+    {
+#if MEMORY_TEST
+        Interlocked.Increment(ref _liveInstances);
+#endif
+    }
+
+    protected override bool ReleaseHandle()
+    {
+#if MEMORY_TEST
+        Interlocked.Decrement(ref _liveInstances);
+#endif
+
+        return NativeMethods.StreamingTableDestroy(handle) == DataFusionErrorCode.Ok;
+    }
+}
@@ -0,0 +1,71 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using Apache.Arrow;
+using Apache.Arrow.Types;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Native callbacks forwarding to a host <see cref="ScalarUdf"/>.
+/// </summary>
+internal static unsafe class ScalarUdfCallbacks
+{
+    internal static NativeScalarUdfCallbacks Create(ScalarUdf udf)
+    {
+        return new NativeScalarUdfCallbacks
+        {
+            UserData = HostObjectHandle.Alloc(udf),
+            Invoke = &Invoke,
+            Release = &HostObjectHandle.Release
+        };
+    }
+
+    /// <summary>
+    /// Imports argument columns passed by the native library as a struct array.
+    /// </summary>
+    /// <remarks>
+    /// The native library keeps ownership of the source memory, so the batch must be disposed before the callback returns.
+    /// </remarks>
+    internal static RecordBatch ImportArguments(Apache.Arrow.C.CArrowSchema* argsSchema, Apache.Arrow.C.CArrowArray* argsArray)
+    {
+        var schema = Apache.Arrow.C.CArrowSchemaImporter.ImportSchema(argsSchema);
+        return Apache.Arrow.C.CArrowArrayImporter.ImportRecordBatch(argsArray, schema);
+    }
+
+    /// <summary>
+    /// Exports an array returned by host code after checking it has the declared type.
+    /// </summary>
+    /// <remarks>
+    /// The native library reads the buffers according to the declared type, so a mismatch must never reach it.
+    /// </remarks>
+    internal static void ExportResult(IArrowArray result, IArrowType expectedType, Apache.Arrow.C.CArrowArray* resultOut)
+    {
+        if (result.Data.DataType.TypeId != expectedType.TypeId)
+            throw new InvalidOperationException($"Function returned {result.Data.DataType.Name} values, but {expectedType.Name} was declared.");
+
+        Apache.Arrow.C.CArrowArrayExporter.ExportArray(result, resultOut);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode Invoke(
+        IntPtr userData,
+        Apache.Arrow.C.CArrowSchema* argsSchema,
+        Apache.Arrow.C.CArrowArray* argsArray,
+        Apache.Arrow.C.CArrowArray* resultOut)
+    {
+        try
+        {
+            var udf = HostObjectHandle.Get<ScalarUdf>(userData);
+
+            using var args = ImportArguments(argsSchema, argsArray);
+            using var result = udf.Invoke([.. args.Arrays], args.Length);
+            ExportResult(result, udf.ReturnType, resultOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Native callbacks forwarding to a host <see cref="TableFunction"/>.
+/// </summary>
+internal static unsafe class TableFunctionCallbacks
+{
+    internal static NativeTableFunctionCallbacks Create(TableFunction function)
+    {
+        return new NativeTableFunctionCallbacks
+        {
+            UserData = HostObjectHandle.Alloc(function),
+            Call = &Call,
+            Release = &HostObjectHandle.Release
+        };
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode Call(IntPtr userData, BytesData argsData, Apache.Arrow.C.CArrowArrayStream* streamOut)
+    {
+        try
+        {
+            var function = HostObjectHandle.Get<TableFunction>(userData);
+
+            var args = Proto.TableFunctionArgs.Parser.ParseFrom(argsData.ToArray()).Args.ToList();
+            var stream = function.Call(args);
+            Apache.Arrow.C.CArrowArrayStreamExporter.ExportArrayStream(stream, streamOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Native callbacks forwarding to a host <see cref="TableProvider"/>.
+/// </summary>
+internal static unsafe class TableProviderCallbacks
+{
+    internal static NativeTableProviderCallbacks Create(TableProvider provider)
+    {
+        return new NativeTableProviderCallbacks
+        {
+            UserData = HostObjectHandle.Alloc(provider),
+            Schema = &GetSchema,
+            Scan = &Scan,
+            SupportsFiltersPushdown = &SupportsFiltersPushdown,
+            Release = &HostObjectHandle.Release
+        };
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode GetSchema(IntPtr userData, Apache.Arrow.C.CArrowSchema* schemaOut)
+    {
+        try
+        {
+            var provider = HostObjectHandle.Get<TableProvider>(userData);
+            Apache.Arrow.C.CArrowSchemaExporter.ExportSchema(provider.Schema, schemaOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode Scan(
+        IntPtr userData,
+        uint* projectionPtr,
+        uint projectionLength,
+        BytesData filtersData,
+        long limit,
+        Apache.Arrow.C.CArrowArrayStream* streamOut)
+    {
+        try
+        {
+            var provider = HostObjectHandle.Get<TableProvider>(userData);
+
+            int[]? projection = null;
+            if (projectionPtr != null)
+            {
+                projection = new int[projectionLength];
+                for (var i = 0; i < projection.Length; i++)
+                    projection[i] = (int)projectionPtr[i];
+            }
+
+            var stream = provider.Scan(projection, ParseFilters(filtersData), limit < 0 ? null : limit);
+            Apache.Arrow.C.CArrowArrayStreamExporter.ExportArrayStream(stream, streamOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode SupportsFiltersPushdown(
+        IntPtr userData,
+        BytesData filtersData,
+        int* resultsPtr,
+        uint resultsLength)
+    {
+        try
+        {
+            var provider = HostObjectHandle.Get<TableProvider>(userData);
+            var results = provider.SupportsFiltersPushdown(ParseFilters(filtersData));
+            if (results.Count != resultsLength)
+                return DataFusionErrorCode.InvalidArgument;
+
+            for (var i = 0; i < results.Count; i++)
+                resultsPtr[i] = (int)results[i];
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    private static List<Proto.LogicalExprNode> ParseFilters(BytesData filtersData)
+    {
+        return Proto.LogicalExprNodeCollection.Parser.ParseFrom(filtersData.ToArray()).LogicalExprNodes.ToList();
+    }
+}
@@ -0,0 +1,77 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Native callbacks forwarding to a host <see cref="WindowUdf"/>.
+/// </summary>
+internal static unsafe class WindowUdfCallbacks
+{
+    internal static NativeWindowUdfCallbacks Create(WindowUdf udf)
+    {
+        var includeRank = udf.IncludeRank;
+        var callbacks = new NativeWindowUdfCallbacks
+        {
+            UserData = HostObjectHandle.Alloc(udf),
+            Release = &HostObjectHandle.Release
+        };
+
+        // The native library picks the evaluation mode from the callback that is set
+        if (includeRank)
+            callbacks.EvaluateAllWithRank = &EvaluateAllWithRank;
+        else
+            callbacks.EvaluateAll = &EvaluateAll;
+
+        return callbacks;
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode EvaluateAll(
+        IntPtr userData,
+        Apache.Arrow.C.CArrowSchema* argsSchema,
+        Apache.Arrow.C.CArrowArray* argsArray,
+        Apache.Arrow.C.CArrowArray* resultOut)
+    {
+        try
+        {
+            var udf = HostObjectHandle.Get<WindowUdf>(userData);
+
+            using var args = ScalarUdfCallbacks.ImportArguments(argsSchema, argsArray);
+            using var result = udf.Evaluate([.. args.Arrays], args.Length);
+            ScalarUdfCallbacks.ExportResult(result, udf.ReturnType, resultOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode EvaluateAllWithRank(
+        IntPtr userData,
+        ulong rowCount,
+        NativeRankRange* ranksPtr,
+        ulong ranksLength,
+        Apache.Arrow.C.CArrowArray* resultOut)
+    {
+        try
+        {
+            var udf = HostObjectHandle.Get<WindowUdf>(userData);
+
+            var ranks = new Range[ranksLength];
+            for (var i = 0; i < ranks.Length; i++)
+                ranks[i] = new Range((int)ranksPtr[i].Start, (int)ranksPtr[i].End);
+
+            using var result = udf.EvaluateWithRank((int)rowCount, ranks);
+            ScalarUdfCallbacks.ExportResult(result, udf.ReturnType, resultOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return HostObjectHandle.ToErrorCode(ex);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// The type of join performed by <see cref="DataFrame.Join"/>.
+/// </summary>
+public enum JoinType
+{
+    /// <summary>
+    /// Rows with matching keys on both sides.
+    /// </summary>
+    Inner,
+
+    /// <summary>
+    /// All rows of the left side, with nulls for the right side where no row matches.
+    /// </summary>
+    Left,
+
+    /// <summary>
+    /// All rows of the right side, with nulls for the left side where no row matches.
+    /// </summary>
+    Right,
+
+    /// <summary>
+    /// All rows of both sides, with nulls where no row matches.
+    /// </summary>
+    Full,
+
+    /// <summary>
+    /// Rows of the left side that have a match on the right side, with left columns only.
+    /// </summary>
+    LeftSemi,
+
+    /// <summary>
+    /// Rows of the left side that have no match on the right side, with left columns only.
+    /// </summary>
+    LeftAnti,
+
+    /// <summary>
+    /// Rows of the right side that have a match on the left side, with right columns only.
+    /// </summary>
+    RightSemi,
+
+    /// <summary>
+    /// Rows of the right side that have no match on the left side, with right columns only.
+    /// </summary>
+    RightAnti,
+
+    /// <summary>
+    /// All rows of the left side with an additional boolean column telling whether a right row matches.
+    /// </summary>
+    LeftMark,
+
+    /// <summary>
+    /// All rows of the right side with an additional boolean column telling whether a left row matches.
+    /// </summary>
+    RightMark
+}
+
+internal static class ProtoJoinTypeExtensions
+{
+    internal static Proto.JoinType ToProto(this JoinType joinType) => joinType switch
+    {
+        JoinType.Inner => Proto.JoinType.Inner,
+        JoinType.Left => Proto.JoinType.Left,
+        JoinType.Right => Proto.JoinType.Right,
+        JoinType.Full => Proto.JoinType.Full,
+        JoinType.LeftSemi => Proto.JoinType.Leftsemi,
+        JoinType.LeftAnti => Proto.JoinType.Leftanti,
+        JoinType.RightSemi => Proto.JoinType.Rightsemi,
+        JoinType.RightAnti => Proto.JoinType.Rightanti,
+        JoinType.LeftMark => Proto.JoinType.Leftmark,
+        JoinType.RightMark => Proto.JoinType.Rightmark,
+        _ => throw new ArgumentOutOfRangeException(nameof(joinType), joinType, "Invalid JoinType value")
+    };
+}
@@ -0,0 +1,33 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Memory pool used to limit the memory consumed by query execution.
+/// </summary>
+public enum MemoryPoolType
+{
+    /// <summary>
+    /// No memory limit is enforced.
+    /// </summary>
+    Unbounded,
+
+    /// <summary>
+    /// First-come first-served pool; operators that cannot reserve memory spill to disk or fail.
+    /// </summary>
+    Greedy,
+
+    /// <summary>
+    /// Pool that divides memory evenly between spillable operators.
+    /// </summary>
+    FairSpill
+}
+
+internal static class ProtoMemoryPoolTypeExtensions
+{
+    internal static Proto.MemoryPoolType ToProto(this MemoryPoolType type) => type switch
+    {
+        MemoryPoolType.Unbounded => Proto.MemoryPoolType.Unbounded,
+        MemoryPoolType.Greedy => Proto.MemoryPoolType.Greedy,
+        MemoryPoolType.FairSpill => Proto.MemoryPoolType.FairSpill,
+        _ => throw new ArgumentOutOfRangeException(nameof(type), type, "Invalid MemoryPoolType value")
+    };
+}
@@ -0,0 +1,58 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Configuration of the runtime environment of a <see cref="SessionContext"/>: memory limits and spilling to disk.
+/// </summary>
+public sealed class RuntimeEnvConfig
+{
+    /// <summary>
+    /// Memory pool type. If null, no memory limit is enforced.
+    /// </summary>
+    public MemoryPoolType? MemoryPoolType { get; set; }
+
+    /// <summary>
+    /// Memory pool size in bytes. Required for <see cref="DataFusionSharp.MemoryPoolType.Greedy"/>
+    /// and <see cref="DataFusionSharp.MemoryPoolType.FairSpill"/> pools.
+    /// </summary>
+    public ulong? MemoryLimit { get; set; }
+
+    /// <summary>
+    /// Disk manager mode. If null, spill files are created in the OS temporary directory.
+    /// </summary>
+    public DiskManagerMode? DiskManagerMode { get; set; }
+
+    /// <summary>
+    /// Directories for spill files. Required when <see cref="DiskManagerMode"/> is <see cref="DataFusionSharp.DiskManagerMode.Directories"/>.
+    /// </summary>
+    public IReadOnlyList<string>? TempDirectories { get; set; }
+
+    /// <summary>
+    /// Maximum size in bytes of all spill files. If null, DataFusion uses its default (100GB).
+    /// </summary>
+    public ulong? MaxTempDirectorySize { get; set; }
+}
+
+internal static class ProtoRuntimeEnvConfigExtensions
+{
+    internal static Proto.RuntimeEnvConfig ToProto(this RuntimeEnvConfig config)
+    {
+        var proto = new Proto.RuntimeEnvConfig();
+
+        if (config.MemoryPoolType.HasValue)
+            proto.MemoryPoolType = config.MemoryPoolType.Value.ToProto();
+
+        if (config.MemoryLimit.HasValue)
+            proto.MemoryLimit = config.MemoryLimit.Value;
+
+        if (config.DiskManagerMode.HasValue)
+            proto.DiskManagerMode = config.DiskManagerMode.Value.ToProto();
+
+        if (config.TempDirectories is { Count: > 0 })
+            proto.TempDirectories.AddRange(config.TempDirectories);
+
+        if (config.MaxTempDirectorySize.HasValue)
+            proto.MaxTempDirectorySize = config.MaxTempDirectorySize.Value;
+
+        return proto;
+    }
+}
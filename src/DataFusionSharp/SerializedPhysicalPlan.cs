@@ -0,0 +1,8 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// A physical plan serialized with <see cref="DataFrame.ToPhysicalPlanBytesAsync"/>.
+/// </summary>
+/// <param name="Plan">The protobuf-encoded <c>datafusion.PhysicalPlanNode</c>.</param>
+/// <param name="PartitionCount">The number of output partitions, each of which can be executed independently.</param>
+public sealed record SerializedPhysicalPlan(ReadOnlyMemory<byte> Plan, int PartitionCount);
@@ -0,0 +1,134 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Configuration of a <see cref="SessionContext"/>.
+/// </summary>
+public sealed class SessionConfig
+{
+    /// <summary>
+    /// Number of partitions for query execution. If null, DataFusion uses its default (the number of CPU cores).
+    /// </summary>
+    public ulong? TargetPartitions { get; set; }
+
+    /// <summary>
+    /// Default batch size while creating new batches. If null, DataFusion uses its default (8192).
+    /// </summary>
+    public ulong? BatchSize { get; set; }
+
+    /// <summary>
+    /// Time zone used for timestamp functions such as <c>now()</c>, e.g. "+00:00" or "Europe/Kyiv".
+    /// If null, DataFusion uses its default.
+    /// </summary>
+    public string? TimeZone { get; set; }
+
+    /// <summary>
+    /// Whether the <c>information_schema</c> virtual tables are available. If null, DataFusion uses its default (false).
+    /// </summary>
+    public bool? InformationSchema { get; set; }
+
+    /// <summary>
+    /// Name of the default catalog. If null, DataFusion uses its default ("datafusion").
+    /// </summary>
+    public string? DefaultCatalog { get; set; }
+
+    /// <summary>
+    /// Name of the default schema. If null, DataFusion uses its default ("public").
+    /// </summary>
+    public string? DefaultSchema { get; set; }
+
+    /// <summary>
+    /// Whether the default catalog and schema should be created. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? CreateDefaultCatalogAndSchema { get; set; }
+
+    /// <summary>
+    /// Whether statistics should be collected when first creating a table. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? CollectStatistics { get; set; }
+
+    /// <summary>
+    /// Whether joins should be repartitioned to improve parallelism. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? RepartitionJoins { get; set; }
+
+    /// <summary>
+    /// Whether aggregations should be repartitioned to improve parallelism. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? RepartitionAggregations { get; set; }
+
+    /// <summary>
+    /// Whether window functions should be repartitioned to improve parallelism. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? RepartitionWindows { get; set; }
+
+    /// <summary>
+    /// Whether sorts should be repartitioned to improve parallelism. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? RepartitionSorts { get; set; }
+
+    /// <summary>
+    /// Whether file scans should be repartitioned to improve parallelism. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? RepartitionFileScans { get; set; }
+
+    /// <summary>
+    /// Free-form configuration options keyed by their full name, e.g. "datafusion.execution.parquet.pushdown_filters".
+    /// Applied before the typed properties above, so typed properties take precedence.
+    /// </summary>
+    public IReadOnlyDictionary<string, string>? Options { get; set; }
+}
+
+internal static class ProtoSessionConfigExtensions
+{
+    internal static Proto.SessionConfig ToProto(this SessionConfig config)
+    {
+        var proto = new Proto.SessionConfig();
+
+        if (config.TargetPartitions.HasValue)
+            proto.TargetPartitions = config.TargetPartitions.Value;
+
+        if (config.BatchSize.HasValue)
+            proto.BatchSize = config.BatchSize.Value;
+
+        if (config.TimeZone is not null)
+            proto.TimeZone = config.TimeZone;
+
+        if (config.InformationSchema.HasValue)
+            proto.InformationSchema = config.InformationSchema.Value;
+
+        if (config.DefaultCatalog is not null)
+            proto.DefaultCatalog = config.DefaultCatalog;
+
+        if (config.DefaultSchema is not null)
+            proto.DefaultSchema = config.DefaultSchema;
+
+        if (config.CreateDefaultCatalogAndSchema.HasValue)
+            proto.CreateDefaultCatalogAndSchema = config.CreateDefaultCatalogAndSchema.Value;
+
+        if (config.CollectStatistics.HasValue)
+            proto.CollectStatistics = config.CollectStatistics.Value;
+
+        if (config.RepartitionJoins.HasValue)
+            proto.RepartitionJoins = config.RepartitionJoins.Value;
+
+        if (config.RepartitionAggregations.HasValue)
+            proto.RepartitionAggregations = config.RepartitionAggregations.Value;
+
+        if (config.RepartitionWindows.HasValue)
+            proto.RepartitionWindows = config.RepartitionWindows.Value;
+
+        if (config.RepartitionSorts.HasValue)
+            proto.RepartitionSorts = config.RepartitionSorts.Value;
+
+        if (config.RepartitionFileScans.HasValue)
+            proto.RepartitionFileScans = config.RepartitionFileScans.Value;
+
+        if (config.Options is not null)
+        {
+            foreach (var (key, value) in config.Options)
+                proto.Options[key] = value;
+        }
+
+        return proto;
+    }
+}
@@ -1,9 +1,11 @@
+using System.Collections.ObjectModel;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
 using Apache.Arrow;
 using DataFusionSharp.Formats.Csv;
 using DataFusionSharp.Formats.Json;
 using DataFusionSharp.Formats.Parquet;
+using DataFusionSharp.Functions;
 using DataFusionSharp.Interop;
 using DataFusionSharp.ObjectStore;
 
@@ -25,6 +27,8 @@ public sealed class SessionContext : IDisposable
     /// Gets the runtime that owns this session context.
     /// </summary>
     public DataFusionRuntime Runtime { get; }
+
+    internal SessionContextSafeHandle Handle => _handle;
     
     internal SessionContext(DataFusionRuntime runtime, SessionContextSafeHandle handle)
     {
@@ -159,6 +163,129 @@ public sealed class SessionContext : IDisposable
         }
     }
 
+    /// <summary>
+    /// Reads one or more CSV files into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePaths">The paths to the CSV files. All files must share the same schema.</param>
+    /// <param name="options">Optional CSV read options to customize parsing behavior.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the files cannot be read.</exception>
+    public async Task<DataFrame> ReadCsvAsync(
+        IReadOnlyList<string> filePaths,
+        CsvReadOptions? options = null,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePaths);
+
+        var paths = filePaths.ToArray();
+        Task<DataFrameSafeHandle> readTask;
+
+        using (var optionsData = PinnedBytesData.FromMessage(options?.ToProto()))
+        {
+            unsafe
+            {
+                var op = new AsyncOperation<DataFrameSafeHandle>(cancellationToken);
+                var result = NativeMethods.ContextReadCsv(
+                    _handle,
+                    paths,
+                    (uint)paths.Length,
+                    optionsData.ToBytesData(),
+                    &CallbackForSqlAsync,
+                    op.GetHandle(),
+                    out var cancellationTokenHandle);
+                op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start reading CSV files.");
+                readTask = op.Task;
+            }
+        }
+
+        var dataFrameSafeHandle = await readTask.ConfigureAwait(false);
+
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Reads one or more JSON files into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePaths">The paths to the JSON files. All files must share the same schema.</param>
+    /// <param name="options">Optional JSON read options to customize parsing behavior.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the files cannot be read.</exception>
+    public async Task<DataFrame> ReadJsonAsync(
+        IReadOnlyList<string> filePaths,
+        JsonReadOptions? options = null,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePaths);
+
+        var paths = filePaths.ToArray();
+        Task<DataFrameSafeHandle> readTask;
+
+        using (var optionsData = PinnedBytesData.FromMessage(options?.ToProto()))
+        {
+            unsafe
+            {
+                var op = new AsyncOperation<DataFrameSafeHandle>(cancellationToken);
+                var result = NativeMethods.ContextReadJson(
+                    _handle,
+                    paths,
+                    (uint)paths.Length,
+                    optionsData.ToBytesData(),
+                    &CallbackForSqlAsync,
+                    op.GetHandle(),
+                    out var cancellationTokenHandle);
+                op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start reading JSON files.");
+                readTask = op.Task;
+            }
+        }
+
+        var dataFrameSafeHandle = await readTask.ConfigureAwait(false);
+
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Reads one or more Parquet files into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePaths">The paths to the Parquet files. All files must share the same schema.</param>
+    /// <param name="options">Optional Parquet read options to customize reading behavior.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the files cannot be read.</exception>
+    public async Task<DataFrame> ReadParquetAsync(
+        IReadOnlyList<string> filePaths,
+        ParquetReadOptions? options = null,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePaths);
+
+        var paths = filePaths.ToArray();
+        Task<DataFrameSafeHandle> readTask;
+
+        using (var optionsData = PinnedBytesData.FromMessage(options?.ToProto()))
+        {
+            unsafe
+            {
+                var op = new AsyncOperation<DataFrameSafeHandle>(cancellationToken);
+                var result = NativeMethods.ContextReadParquet(
+                    _handle,
+                    paths,
+                    (uint)paths.Length,
+                    optionsData.ToBytesData(),
+                    &CallbackForSqlAsync,
+                    op.GetHandle(),
+                    out var cancellationTokenHandle);
+                op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start reading Parquet files.");
+                readTask = op.Task;
+            }
+        }
+
+        var dataFrameSafeHandle = await readTask.ConfigureAwait(false);
+
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
     /// <summary>
     /// Registers an in-memory Arrow RecordBatch as a table in this session.
     /// </summary>
@@ -194,6 +321,320 @@ public sealed class SessionContext : IDisposable
         }
     }
 
+    /// <summary>
+    /// Registers in-memory Arrow RecordBatches as a single table in this session.
+    /// </summary>
+    /// <remarks>
+    /// It uses Arrow IPC format to transfer the RecordBatches to the native side.
+    /// Batches are distributed round-robin across partitions so the table can be scanned in parallel.
+    /// </remarks>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="schema">The schema of the table. All batches must share it.</param>
+    /// <param name="batches">The RecordBatches to register. An empty sequence registers an empty table.</param>
+    /// <param name="partitionCount">The number of partitions to spread the batches across. 0 keeps all batches in one partition.</param>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    public void RegisterBatches(string tableName, Schema schema, IEnumerable<RecordBatch> batches, int partitionCount = 0)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(schema);
+        ArgumentNullException.ThrowIfNull(batches);
+        ArgumentOutOfRangeException.ThrowIfNegative(partitionCount);
+
+        using var memoryStream = new MemoryStream();
+        using (var writer = new Apache.Arrow.Ipc.ArrowStreamWriter(memoryStream, schema, true))
+        {
+            writer.WriteStart();
+            foreach (var batch in batches)
+                writer.WriteRecordBatch(batch);
+            writer.WriteEnd();
+        }
+        using var memoryHandle = memoryStream.GetBuffer().AsMemory().Pin();
+
+        unsafe
+        {
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterBatches(
+                _handle,
+                tableName,
+                BytesData.FromPinned(memoryHandle, (int)memoryStream.Length),
+                (uint)partitionCount,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start record batches registration.");
+        }
+    }
+
+    /// <summary>
+    /// Registers in-memory Arrow RecordBatches as a single table in this session without copying their buffers.
+    /// </summary>
+    /// <remarks>
+    /// It uses the Arrow C Data Interface to hand the buffers of the RecordBatches to the native side, which keeps
+    /// them alive until the table is dropped. Exporting may move buffer ownership, so do not read the batches
+    /// after this call; they should still be disposed.
+    /// </remarks>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="schema">The schema of the table. All batches must share it.</param>
+    /// <param name="batches">The RecordBatches to register. An empty list registers an empty table.</param>
+    /// <param name="partitionCount">The number of partitions to spread the batches across. 0 keeps all batches in one partition.</param>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    public void ImportBatches(string tableName, Schema schema, IReadOnlyList<RecordBatch> batches, int partitionCount = 0)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(schema);
+        ArgumentNullException.ThrowIfNull(batches);
+        ArgumentOutOfRangeException.ThrowIfNegative(partitionCount);
+
+        unsafe
+        {
+            var cSchema = Apache.Arrow.C.CArrowSchema.Create();
+            var cArrays = (Apache.Arrow.C.CArrowArray*)NativeMemory.AllocZeroed(
+                (nuint)Math.Max(batches.Count, 1),
+                (nuint)sizeof(Apache.Arrow.C.CArrowArray));
+            var exported = 0;
+            try
+            {
+                Apache.Arrow.C.CArrowSchemaExporter.ExportSchema(schema, cSchema);
+                for (; exported < batches.Count; exported++)
+                    Apache.Arrow.C.CArrowArrayExporter.ExportRecordBatch(batches[exported], cArrays + exported);
+
+                // Ownership of the exported arrays moves to the native side, even when registration fails
+                exported = 0;
+                var op = new SyncVoidOperation();
+                var result = NativeMethods.ContextRegisterFfiBatches(
+                    _handle,
+                    tableName,
+                    cSchema,
+                    cArrays,
+                    (uint)batches.Count,
+                    (uint)partitionCount,
+                    &GenericCallbacks.CallbackForVoidSync,
+                    op.GetHandle());
+                op.EnsureNativeCall(result, "Failed to start record batches import.");
+            }
+            finally
+            {
+                for (var i = 0; i < exported; i++)
+                    Apache.Arrow.C.CArrowArray.CallReleaseFunc(cArrays + i);
+                NativeMemory.Free(cArrays);
+                Apache.Arrow.C.CArrowSchema.Free(cSchema);
+            }
+        }
+    }
+
+    /// <summary>
+    /// Registers a table whose record batches are pushed by the application over time.
+    /// </summary>
+    /// <remarks>
+    /// Queries over the table consume the batches pushed through the returned <see cref="StreamingTable"/>.
+    /// Up to <paramref name="capacity"/> batches are buffered before pushes wait for a query to consume them.
+    /// The table can be scanned by one query at a time, so a self-join over it fails.
+    /// </remarks>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="schema">The schema of the pushed batches.</param>
+    /// <param name="capacity">The number of batches buffered before pushes wait. 0 is treated as 1.</param>
+    /// <param name="unbounded">Whether the table is planned as an infinite source.</param>
+    /// <returns>The <see cref="StreamingTable"/> used to push batches and finish the stream.</returns>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    public StreamingTable RegisterStreamingTable(string tableName, Schema schema, int capacity = 16, bool unbounded = false)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(schema);
+        ArgumentOutOfRangeException.ThrowIfNegative(capacity);
+
+        IntPtr tableHandle;
+        unsafe
+        {
+            var cSchema = Apache.Arrow.C.CArrowSchema.Create();
+            try
+            {
+                Apache.Arrow.C.CArrowSchemaExporter.ExportSchema(schema, cSchema);
+
+                var op = new SyncOperation<IntPtr>();
+                var result = NativeMethods.ContextRegisterStreamingTable(
+                    _handle,
+                    tableName,
+                    cSchema,
+                    (uint)capacity,
+                    unbounded,
+                    &GenericCallbacks.CallbackForHandleSync,
+                    op.GetHandle());
+                tableHandle = op.EnsureNativeCall(result, "Failed to start streaming table registration.");
+            }
+            finally
+            {
+                Apache.Arrow.C.CArrowSchema.Free(cSchema);
+            }
+        }
+
+        return new StreamingTable(this, schema, new StreamingTableSafeHandle(tableHandle));
+    }
+
+    /// <summary>
+    /// Registers a table whose schema, scans and filter pushdown are implemented by the application.
+    /// </summary>
+    /// <remarks>
+    /// The session keeps a reference to the provider until the table is dropped.
+    /// </remarks>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="provider">The provider answering schema, scan and filter pushdown requests.</param>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    public void RegisterTableProvider(string tableName, TableProvider provider)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(provider);
+
+        unsafe
+        {
+            // The native side owns the callbacks from here on and releases them even if registration fails
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterTableProvider(
+                _handle,
+                tableName,
+                TableProviderCallbacks.Create(provider),
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start table provider registration.");
+        }
+    }
+
+    /// <summary>
+    /// Registers a scalar user-defined function implemented by the application.
+    /// </summary>
+    /// <remarks>
+    /// A function with the same name replaces the existing one. The session keeps a reference to the function until it is replaced or the session is disposed.
+    /// </remarks>
+    /// <param name="udf">The function to register.</param>
+    /// <exception cref="DataFusionException">Thrown when the function signature is invalid.</exception>
+    public void RegisterScalarUdf(ScalarUdf udf)
+    {
+        ArgumentNullException.ThrowIfNull(udf);
+
+        using var signatureData = PinnedBytesData.FromMessage(udf.ToProto());
+
+        unsafe
+        {
+            // The native side owns the callbacks from here on and releases them even if registration fails
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterScalarUdf(
+                _handle,
+                signatureData.ToBytesData(),
+                ScalarUdfCallbacks.Create(udf),
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start scalar UDF registration.");
+        }
+    }
+
+    /// <summary>
+    /// Registers an aggregate user-defined function implemented by the application.
+    /// </summary>
+    /// <remarks>
+    /// A function with the same name replaces the existing one. The session keeps a reference to the function until it is replaced or the session is disposed.
+    /// </remarks>
+    /// <param name="udf">The function to register.</param>
+    /// <exception cref="DataFusionException">Thrown when the function signature is invalid.</exception>
+    public void RegisterAggregateUdf(AggregateUdf udf)
+    {
+        ArgumentNullException.ThrowIfNull(udf);
+
+        using var signatureData = PinnedBytesData.FromMessage(udf.ToProto());
+
+        unsafe
+        {
+            // The native side owns the callbacks from here on and releases them even if registration fails
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterAggregateUdf(
+                _handle,
+                signatureData.ToBytesData(),
+                AggregateUdfCallbacks.Create(udf),
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start aggregate UDF registration.");
+        }
+    }
+
+    /// <summary>
+    /// Registers a window user-defined function implemented by the application.
+    /// </summary>
+    /// <remarks>
+    /// A function with the same name replaces the existing one. The session keeps a reference to the function until it is replaced or the session is disposed.
+    /// </remarks>
+    /// <param name="udf">The function to register.</param>
+    /// <exception cref="DataFusionException">Thrown when the function signature is invalid.</exception>
+    public void RegisterWindowUdf(WindowUdf udf)
+    {
+        ArgumentNullException.ThrowIfNull(udf);
+
+        using var signatureData = PinnedBytesData.FromMessage(udf.ToProto());
+
+        unsafe
+        {
+            // The native side owns the callbacks from here on and releases them even if registration fails
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterWindowUdf(
+                _handle,
+                signatureData.ToBytesData(),
+                WindowUdfCallbacks.Create(udf),
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start window UDF registration.");
+        }
+    }
+
+    /// <summary>
+    /// Registers a table function implemented by the application, callable from SQL in <c>FROM</c> clauses.
+    /// </summary>
+    /// <remarks>
+    /// A function with the same name replaces the existing one. The session keeps a reference to the function until it is replaced or the session is disposed.
+    /// </remarks>
+    /// <param name="function">The function to register.</param>
+    /// <exception cref="DataFusionException">Thrown when registration fails.</exception>
+    public void RegisterTableFunction(TableFunction function)
+    {
+        ArgumentNullException.ThrowIfNull(function);
+
+        unsafe
+        {
+            // The native side owns the callbacks from here on and releases them even if registration fails
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterTableFunction(
+                _handle,
+                function.Name,
+                TableFunctionCallbacks.Create(function),
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start table function registration.");
+        }
+    }
+
+    /// <summary>
+    /// Registers a DataFrame as a view, so later SQL can refer to it by name.
+    /// </summary>
+    /// <remarks>
+    /// The view keeps the query of the DataFrame and is re-evaluated on every query; use <see cref="DataFrame.CacheAsync"/> first to register materialized results.
+    /// The DataFrame can be disposed after registration.
+    /// </remarks>
+    /// <param name="viewName">The bare or <c>catalog.schema.table</c> qualified name of the view.</param>
+    /// <param name="dataFrame">The DataFrame to register.</param>
+    /// <exception cref="DataFusionException">Thrown when view registration fails.</exception>
+    public void RegisterView(string viewName, DataFrame dataFrame)
+    {
+        ArgumentNullException.ThrowIfNull(viewName);
+        ArgumentNullException.ThrowIfNull(dataFrame);
+
+        unsafe
+        {
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterView(
+                _handle,
+                viewName,
+                dataFrame.Handle,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start view registration.");
+        }
+    }
+
     /// <summary>
     /// Deregisters a table from this session.
     /// </summary>
@@ -215,6 +656,253 @@ public sealed class SessionContext : IDisposable
         }
     }
 
+    /// <summary>
+    /// Creates an empty in-memory catalog in this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <exception cref="DataFusionException">Thrown when the catalog already exists.</exception>
+    public void CreateCatalog(string catalogName)
+    {
+        ArgumentNullException.ThrowIfNull(catalogName);
+
+        unsafe
+        {
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextCreateCatalog(
+                _handle,
+                catalogName,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start catalog creation.");
+        }
+    }
+
+    /// <summary>
+    /// Drops a catalog from this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <param name="cascade">Whether to drop the catalog even if it still contains tables.</param>
+    /// <exception cref="DataFusionException">Thrown when the catalog does not exist, is the default catalog, or is not empty and <paramref name="cascade"/> is false.</exception>
+    public void DropCatalog(string catalogName, bool cascade = false)
+    {
+        ArgumentNullException.ThrowIfNull(catalogName);
+
+        unsafe
+        {
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextDropCatalog(
+                _handle,
+                catalogName,
+                cascade,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start catalog drop.");
+        }
+    }
+
+    /// <summary>
+    /// Lists the names of all catalogs in this session.
+    /// </summary>
+    /// <returns>The catalog names in alphabetical order.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public IReadOnlyList<string> GetCatalogNames()
+    {
+        byte[] bytes;
+        unsafe
+        {
+            var op = new SyncOperation<byte[]>();
+            var result = NativeMethods.ContextCatalogNames(
+                _handle,
+                &GenericCallbacks.CallbackForBytesSync,
+                op.GetHandle());
+            bytes = op.EnsureNativeCall(result, "Failed to start listing catalogs.");
+        }
+
+        return ParseNameList(bytes);
+    }
+
+    /// <summary>
+    /// Creates an empty in-memory schema in an existing catalog of this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <param name="schemaName">The name of the schema.</param>
+    /// <exception cref="DataFusionException">Thrown when the catalog does not exist or the schema already exists.</exception>
+    public void CreateSchema(string catalogName, string schemaName)
+    {
+        ArgumentNullException.ThrowIfNull(catalogName);
+        ArgumentNullException.ThrowIfNull(schemaName);
+
+        unsafe
+        {
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextCreateSchema(
+                _handle,
+                catalogName,
+                schemaName,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start schema creation.");
+        }
+    }
+
+    /// <summary>
+    /// Drops a schema from a catalog of this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <param name="schemaName">The name of the schema.</param>
+    /// <param name="cascade">Whether to drop the schema even if it still contains tables.</param>
+    /// <exception cref="DataFusionException">Thrown when the schema does not exist, is the default schema, or is not empty and <paramref name="cascade"/> is false.</exception>
+    public void DropSchema(string catalogName, string schemaName, bool cascade = false)
+    {
+        ArgumentNullException.ThrowIfNull(catalogName);
+        ArgumentNullException.ThrowIfNull(schemaName);
+
+        unsafe
+        {
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextDropSchema(
+                _handle,
+                catalogName,
+                schemaName,
+                cascade,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start schema drop.");
+        }
+    }
+
+    /// <summary>
+    /// Lists the names of all schemas in a catalog of this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <returns>The schema names in alphabetical order.</returns>
+    /// <exception cref="DataFusionException">Thrown when the catalog does not exist.</exception>
+    public IReadOnlyList<string> GetSchemaNames(string catalogName)
+    {
+        ArgumentNullException.ThrowIfNull(catalogName);
+
+        byte[] bytes;
+        unsafe
+        {
+            var op = new SyncOperation<byte[]>();
+            var result = NativeMethods.ContextSchemaNames(
+                _handle,
+                catalogName,
+                &GenericCallbacks.CallbackForBytesSync,
+                op.GetHandle());
+            bytes = op.EnsureNativeCall(result, "Failed to start listing schemas.");
+        }
+
+        return ParseNameList(bytes);
+    }
+
+    /// <summary>
+    /// Lists the names of all tables in a schema of this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <param name="schemaName">The name of the schema.</param>
+    /// <returns>The table names in alphabetical order.</returns>
+    /// <exception cref="DataFusionException">Thrown when the catalog or schema does not exist.</exception>
+    public IReadOnlyList<string> GetTableNames(string catalogName, string schemaName)
+    {
+        ArgumentNullException.ThrowIfNull(catalogName);
+        ArgumentNullException.ThrowIfNull(schemaName);
+
+        byte[] bytes;
+        unsafe
+        {
+            var op = new SyncOperation<byte[]>();
+            var result = NativeMethods.ContextTableNames(
+                _handle,
+                catalogName,
+                schemaName,
+                &GenericCallbacks.CallbackForBytesSync,
+                op.GetHandle());
+            bytes = op.EnsureNativeCall(result, "Failed to start listing tables.");
+        }
+
+        return ParseNameList(bytes);
+    }
+
+    /// <summary>
+    /// Checks whether a table is registered in this session.
+    /// </summary>
+    /// <param name="tableName">The table name, optionally qualified as "schema.table" or "catalog.schema.table".</param>
+    /// <returns><c>true</c> if the table exists; otherwise <c>false</c>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the lookup fails.</exception>
+    public bool TableExists(string tableName)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+
+        unsafe
+        {
+            var op = new SyncOperation<bool>();
+            var result = NativeMethods.ContextTableExist(
+                _handle,
+                tableName,
+                &GenericCallbacks.CallbackForBoolSync,
+                op.GetHandle());
+            return op.EnsureNativeCall(result, "Failed to start checking table existence.");
+        }
+    }
+
+    /// <summary>
+    /// Gets the schema and kind of a registered table.
+    /// </summary>
+    /// <param name="tableName">The table name, optionally qualified as "schema.table" or "catalog.schema.table".</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the <see cref="TableInfo"/> of the table.</returns>
+    /// <exception cref="DataFusionException">Thrown when the table does not exist.</exception>
+    public Task<TableInfo> GetTableInfoAsync(string tableName, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+
+        unsafe
+        {
+            var op = new AsyncOperation<TableInfo>(cancellationToken);
+            var result = NativeMethods.ContextTableSchema(
+                _handle,
+                tableName,
+                &CallbackForGetTableInfo,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start getting table schema.");
+
+            return op.Task;
+        }
+    }
+
+    /// <summary>
+    /// Creates a DataFrame that reads all rows of a registered table.
+    /// </summary>
+    /// <param name="tableName">The table name, optionally qualified as "schema.table" or "catalog.schema.table".</param>
+    /// <param name="cancellationToken">An optional cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the table does not exist.</exception>
+    public async Task<DataFrame> TableAsync(string tableName, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+
+        Task<DataFrameSafeHandle> tableTask;
+
+        unsafe
+        {
+            var op = new AsyncOperation<DataFrameSafeHandle>(cancellationToken);
+            var result = NativeMethods.ContextTable(
+                _handle,
+                tableName,
+                &CallbackForSqlAsync,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start resolving table.");
+            tableTask = op.Task;
+        }
+
+        var dataFrameSafeHandle = await tableTask.ConfigureAwait(false);
+
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
     /// <summary>
     /// Executes a SQL query and returns the result as a DataFrame.
     /// </summary>
@@ -296,6 +984,160 @@ public sealed class SessionContext : IDisposable
         return new DataFrame(this, dataFrameSafeHandle);
     }
     
+    /// <summary>
+    /// Creates a DataFrame from a logical plan serialized with <see cref="DataFrame.ToLogicalPlanBytes"/>.
+    /// </summary>
+    /// <remarks>
+    /// Tables and functions referenced by the plan are resolved against this session, so they must be registered under the same names as in the session that serialized it.
+    /// </remarks>
+    /// <param name="plan">The protobuf-encoded <c>datafusion.LogicalPlanNode</c>.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the plan cannot be decoded or references unknown tables.</exception>
+    public async Task<DataFrame> FromLogicalPlanAsync(ReadOnlyMemory<byte> plan, CancellationToken cancellationToken = default)
+    {
+        Task<DataFrameSafeHandle> planTask;
+        using (var planHandle = plan.Pin())
+        {
+            unsafe
+            {
+                var op = new AsyncOperation<DataFrameSafeHandle>(cancellationToken);
+                var result = NativeMethods.ContextDataFrameFromLogicalPlan(
+                    _handle,
+                    BytesData.FromPinned(planHandle, plan.Length),
+                    &CallbackForSqlAsync,
+                    op.GetHandle(),
+                    out var cancellationTokenHandle);
+                op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start creating DataFrame from logical plan.");
+                planTask = op.Task;
+            }
+        }
+
+        var dataFrameSafeHandle = await planTask.ConfigureAwait(false);
+
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Executes a single output partition of a physical plan serialized with <see cref="DataFrame.ToPhysicalPlanBytesAsync"/>.
+    /// </summary>
+    /// <remarks>
+    /// Scans of registered tables are resolved by name against this session, so the tables must be registered under the same names
+    /// and scanned with the same number of partitions as in the session that created the plan.
+    /// </remarks>
+    /// <param name="plan">The protobuf-encoded <c>datafusion.PhysicalPlanNode</c>.</param>
+    /// <param name="partition">The zero-based output partition to execute.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task containing a <see cref="DataFrameStream"/> with the batches of the partition.</returns>
+    /// <exception cref="DataFusionException">Thrown when the plan cannot be decoded or the partition is out of range.</exception>
+    public async Task<DataFrameStream> ExecutePhysicalPlanPartitionAsync(
+        ReadOnlyMemory<byte> plan,
+        int partition,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegative(partition);
+
+        Task<(Schema Schema, DataFrameStreamSafeHandle StreamHandle)> executeTask;
+        using (var planHandle = plan.Pin())
+        {
+            unsafe
+            {
+                var op = new AsyncOperation<(Schema Schema, DataFrameStreamSafeHandle StreamHandle)>(cancellationToken);
+                var result = NativeMethods.ContextExecutePhysicalPlanPartition(
+                    _handle,
+                    BytesData.FromPinned(planHandle, plan.Length),
+                    (uint)partition,
+                    &DataFrame.CallbackForExecutedStream,
+                    op.GetHandle(),
+                    out var cancellationTokenHandle);
+                op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start executing physical plan partition.");
+                executeTask = op.Task;
+            }
+        }
+
+        var (schema, streamHandle) = await executeTask.ConfigureAwait(false);
+
+        return new DataFrameStream(null, schema, streamHandle);
+    }
+
+    /// <summary>
+    /// Sets a configuration option of this session, equivalent to <c>SET key = value</c> in SQL.
+    /// </summary>
+    /// <param name="key">The full option name, e.g. "datafusion.execution.batch_size".</param>
+    /// <param name="value">The option value.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel the operation.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when the option is unknown or the value is invalid.</exception>
+    public Task SetOptionAsync(string key, string value, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(key);
+        ArgumentNullException.ThrowIfNull(value);
+
+        unsafe
+        {
+            var op = new AsyncVoidOperation(cancellationToken);
+            var result = NativeMethods.ContextSetOption(
+                _handle,
+                key,
+                value,
+                &GenericCallbacks.CallbackForVoid,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start setting session option.");
+
+            return op.Task;
+        }
+    }
+
+    /// <summary>
+    /// Gets a configuration option of this session.
+    /// </summary>
+    /// <param name="key">The full option name, e.g. "datafusion.execution.batch_size". The lookup is case-insensitive.</param>
+    /// <returns>The <see cref="ConfigEntry"/> with the current value of the option.</returns>
+    /// <exception cref="DataFusionException">Thrown when the option is unknown.</exception>
+    public ConfigEntry GetOption(string key)
+    {
+        ArgumentNullException.ThrowIfNull(key);
+
+        byte[] bytes;
+        unsafe
+        {
+            var op = new SyncOperation<byte[]>();
+            var result = NativeMethods.ContextGetOption(
+                _handle,
+                key,
+                &GenericCallbacks.CallbackForBytesSync,
+                op.GetHandle());
+            bytes = op.EnsureNativeCall(result, "Failed to start getting session option.");
+        }
+
+        return Proto.ConfigEntry.Parser.ParseFrom(bytes).ToConfigEntry();
+    }
+
+    /// <summary>
+    /// Lists all configuration entries of this session with their current values and descriptions.
+    /// </summary>
+    /// <returns>The configuration entries.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public IReadOnlyList<ConfigEntry> GetConfigEntries()
+    {
+        byte[] bytes;
+        unsafe
+        {
+            var op = new SyncOperation<byte[]>();
+            var result = NativeMethods.ContextConfigEntries(
+                _handle,
+                &GenericCallbacks.CallbackForBytesSync,
+                op.GetHandle());
+            bytes = op.EnsureNativeCall(result, "Failed to start listing session configuration entries.");
+        }
+
+        return Proto.ConfigEntries.Parser.ParseFrom(bytes).Entries
+            .Select(e => e.ToConfigEntry())
+            .ToList()
+            .AsReadOnly();
+    }
+
     /// <summary>
     /// Registers a local filesystem object store for the given URL.
     /// </summary>
@@ -483,6 +1325,11 @@ public sealed class SessionContext : IDisposable
         _handle.Dispose();
     }
     
+    private static ReadOnlyCollection<string> ParseNameList(byte[] bytes)
+    {
+        return Proto.NameList.Parser.ParseFrom(bytes).Names.ToList().AsReadOnly();
+    }
+
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
     private static void CallbackForSqlAsync(IntPtr result, IntPtr error, IntPtr handle)
     {
@@ -508,4 +1355,35 @@ public sealed class SessionContext : IDisposable
         else
             op.Complete(dataFrameSafeHandle);
     }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static unsafe void CallbackForGetTableInfo(IntPtr result, IntPtr error, IntPtr handle)
+    {
+        var op = AsyncOperation<TableInfo>.FromHandle(handle);
+
+        if (error != IntPtr.Zero)
+        {
+            if (op is null)
+                return;
+
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            op.Complete(ex);
+            return;
+        }
+
+        var data = (NativeTableSchemaData*)result.ToPointer();
+        TableInfo tableInfo;
+        try
+        {
+            var schema = Apache.Arrow.C.CArrowSchemaImporter.ImportSchema(data->Schema);
+            tableInfo = new TableInfo(schema, (TableKind)data->TableKind);
+        }
+        catch (Exception ex)
+        {
+            op?.Complete(ex);
+            return;
+        }
+
+        op?.Complete(tableInfo);
+    }
 }
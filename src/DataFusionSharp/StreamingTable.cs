@@ -0,0 +1,110 @@
+using Apache.Arrow;
+using DataFusionSharp.Interop;
+
+namespace DataFusionSharp;
+
+/// <summary>
+/// A table whose record batches are pushed by the application over time, for example messages from a queue.
+/// </summary>
+/// <remarks>
+/// Queries over the table consume the pushed batches as a stream, one query at a time, and every batch is
+/// consumed by exactly one query. Pushes wait while the buffer is full, so a producer cannot outrun the queries.
+/// Call <see cref="Finish"/> to let running queries complete once the buffered batches are consumed.
+/// Disposing the table finishes the stream but keeps the table registered in the session.
+/// </remarks>
+public sealed class StreamingTable : IDisposable
+{
+    private readonly StreamingTableSafeHandle _handle;
+
+    /// <summary>
+    /// Gets the <see cref="DataFusionSharp.SessionContext"/> the table is registered in.
+    /// </summary>
+    public SessionContext SessionContext { get; }
+
+    /// <summary>
+    /// Gets the <see cref="Apache.Arrow.Schema" /> of the table.
+    /// </summary>
+    public Schema Schema { get; }
+
+    internal StreamingTable(SessionContext sessionContext, Schema schema, StreamingTableSafeHandle handle)
+    {
+        SessionContext = sessionContext;
+        Schema = schema;
+        _handle = handle;
+    }
+
+    /// <summary>
+    /// Pushes a record batch into the table.
+    /// </summary>
+    /// <param name="batch">The record batch to push. It must match the schema of the table.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel waiting for buffer space.</param>
+    /// <returns>A task that completes once the batch has been accepted into the buffer.</returns>
+    /// <exception cref="DataFusionException">Thrown when the batch does not match the schema or the table is finished.</exception>
+    public Task PushAsync(RecordBatch batch, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(batch);
+
+        return PushAsync([batch], cancellationToken);
+    }
+
+    /// <summary>
+    /// Pushes record batches into the table.
+    /// </summary>
+    /// <remarks>
+    /// It uses Arrow IPC format to transfer the RecordBatches to the native side.
+    /// </remarks>
+    /// <param name="batches">The record batches to push. They must match the schema of the table.</param>
+    /// <param name="cancellationToken">Cancellation token to cancel waiting for buffer space.</param>
+    /// <returns>A task that completes once every batch has been accepted into the buffer.</returns>
+    /// <exception cref="DataFusionException">Thrown when a batch does not match the schema or the table is finished.</exception>
+    public Task PushAsync(IEnumerable<RecordBatch> batches, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(batches);
+
+        using var memoryStream = new MemoryStream();
+        using (var writer = new Apache.Arrow.Ipc.ArrowStreamWriter(memoryStream, Schema, true))
+        {
+            writer.WriteStart();
+            foreach (var batch in batches)
+                writer.WriteRecordBatch(batch);
+            writer.WriteEnd();
+        }
+        using var memoryHandle = memoryStream.GetBuffer().AsMemory().Pin();
+
+        unsafe
+        {
+            var op = new AsyncVoidOperation(cancellationToken);
+            var result = NativeMethods.StreamingTablePushBatch(
+                _handle,
+                BytesData.FromPinned(memoryHandle, (int)memoryStream.Length),
+                &GenericCallbacks.CallbackForVoid,
+                op.GetHandle(),
+                out var cancellationTokenHandle);
+            op.EnsureNativeCall(result, cancellationTokenHandle, "Failed to start pushing record batches.");
+
+            return op.Task;
+        }
+    }
+
+    /// <summary>
+    /// Marks the stream as finished, so queries over the table complete once the buffered batches are consumed.
+    /// </summary>
+    /// <remarks>
+    /// Pushes waiting for a running query to make buffer space are delivered before the stream ends.
+    /// Pushes waiting for a query to start, because the buffer is already full, fail.
+    /// </remarks>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public void Finish()
+    {
+        var errorCode = NativeMethods.StreamingTableFinish(_handle);
+        DataFusionException.ThrowIfError(errorCode, "Failed to finish streaming table");
+    }
+
+    /// <summary>
+    /// Finishes the stream and releases the native resources of the push handle.
+    /// </summary>
+    public void Dispose()
+    {
+        _handle.Dispose();
+    }
+}
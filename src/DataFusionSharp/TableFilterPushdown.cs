@@ -0,0 +1,14 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// How a <see cref="TableProvider"/> handles a filter pushed down into its scans.
+/// </summary>
+public enum TableFilterPushdown
+{
+    /// <summary>The filter is ignored by the provider and is applied by DataFusion.</summary>
+    Unsupported = 0,
+    /// <summary>The provider may use the filter to skip rows, but DataFusion still applies it.</summary>
+    Inexact = 1,
+    /// <summary>The provider returns only rows matching the filter, so DataFusion does not apply it again.</summary>
+    Exact = 2
+}
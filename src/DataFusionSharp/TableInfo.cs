@@ -0,0 +1,23 @@
+using Apache.Arrow;
+
+namespace DataFusionSharp;
+
+/// <summary>
+/// Kind of a registered table.
+/// </summary>
+public enum TableKind
+{
+    /// <summary>An ordinary physical table.</summary>
+    Base = 0,
+    /// <summary>A view, defined by a query.</summary>
+    View = 1,
+    /// <summary>A transient table, such as a CTE or a streaming table.</summary>
+    Temporary = 2
+}
+
+/// <summary>
+/// Describes a registered table.
+/// </summary>
+/// <param name="Schema">The Arrow schema of the table.</param>
+/// <param name="Kind">The kind of the table.</param>
+public sealed record TableInfo(Schema Schema, TableKind Kind);
@@ -0,0 +1,46 @@
+using Apache.Arrow;
+using Apache.Arrow.Ipc;
+
+namespace DataFusionSharp;
+
+/// <summary>
+/// Base class for tables whose data comes from the application, for example a proprietary data source.
+/// </summary>
+/// <remarks>
+/// Register an instance with <see cref="SessionContext.RegisterTableProvider"/> to query it with SQL and DataFrames.
+/// Members are invoked by the native library on its own threads, possibly concurrently, so implementations must be thread-safe.
+/// </remarks>
+public abstract class TableProvider
+{
+    /// <summary>
+    /// Gets the schema of the table. It is read once, when the table is registered.
+    /// </summary>
+    public abstract Schema Schema { get; }
+
+    /// <summary>
+    /// Starts a scan of the table.
+    /// </summary>
+    /// <param name="projection">
+    /// Indices of the columns to return, in the requested order, or null to return all columns.
+    /// The returned batches must contain exactly these columns.
+    /// </param>
+    /// <param name="filters">
+    /// Filters accepted by <see cref="SupportsFiltersPushdown"/>, as DataFusion logical expressions.
+    /// Filters reported as <see cref="TableFilterPushdown.Exact"/> must be applied by the scan.
+    /// </param>
+    /// <param name="limit">The maximum number of rows needed, or null when all rows are needed.</param>
+    /// <returns>A stream of record batches. It is disposed by the native library once the scan ends.</returns>
+    public abstract IArrowArrayStream Scan(IReadOnlyList<int>? projection, IReadOnlyList<Proto.LogicalExprNode> filters, long? limit);
+
+    /// <summary>
+    /// Reports how each filter of a query can be pushed down into scans of the table.
+    /// </summary>
+    /// <param name="filters">The filters of the query, as DataFusion logical expressions.</param>
+    /// <returns>One <see cref="TableFilterPushdown"/> per filter. By default, no filter is pushed down.</returns>
+    public virtual IReadOnlyList<TableFilterPushdown> SupportsFiltersPushdown(IReadOnlyList<Proto.LogicalExprNode> filters)
+    {
+        ArgumentNullException.ThrowIfNull(filters);
+
+        return Enumerable.Repeat(TableFilterPushdown.Unsupported, filters.Count).ToList();
+    }
+}
@@ -29,6 +29,7 @@ Console.WriteLine($"Live SessionContextSafeHandle instances: {DataFusionSharp.In
 Console.WriteLine($"Live DataFrameSafeHandle instances: {DataFusionSharp.Interop.DataFrameSafeHandle.LiveInstances}");
 Console.WriteLine($"Live DataFrameStreamSafeHandle instances: {DataFusionSharp.Interop.DataFrameStreamSafeHandle.LiveInstances}");
 Console.WriteLine($"Live InMemoryStoreSafeHandle instances: {DataFusionSharp.Interop.InMemoryStoreSafeHandle.LiveInstances}");
+Console.WriteLine($"Live StreamingTableSafeHandle instances: {DataFusionSharp.Interop.StreamingTableSafeHandle.LiveInstances}");
 
 Console.WriteLine($"Live AsyncOperation instances: {DataFusionSharp.Interop.AsyncOperation.LiveInstances}");
 Console.WriteLine($"Live AsyncOperation tokens: {DataFusionSharp.Interop.AsyncOperation.LiveCancellationTokens}");
@@ -0,0 +1,149 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class AggregateUdfTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public AggregateUdfTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task RegisterAggregateUdf_GroupByQuery_EvaluatesEachGroup()
+    {
+        // Arrange
+        _context.RegisterAggregateUdf(new MeanUdf());
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT k, my_mean(v) AS m FROM (VALUES ('x', 1.0), ('y', 4.0), ('x', 3.0), ('y', NULL)) t(k, v) GROUP BY k ORDER BY k");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var means = collected.Batches.SelectMany(b => b.Column("m").AsDouble()).ToList();
+        Assert.Equal([2.0, 4.0], means);
+    }
+
+    [Fact]
+    public async Task RegisterAggregateUdf_PartitionedInput_MergesPartialStates()
+    {
+        // Arrange
+        var udf = new MeanUdf();
+        _context.RegisterAggregateUdf(udf);
+        var schema = new Schema([new Field("v", DoubleType.Default, nullable: true)], []);
+        using var batch1 = new RecordBatch(schema, [new DoubleArray.Builder().AppendRange([1.0, 2.0]).Build()], 2);
+        using var batch2 = new RecordBatch(schema, [new DoubleArray.Builder().Append(6.0).Build()], 1);
+        _context.RegisterBatches("numbers", schema, [batch1, batch2], partitionCount: 2);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT my_mean(v) AS m FROM numbers");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var mean = Assert.Single(collected.Batches.SelectMany(b => b.Column("m").AsDouble()));
+        Assert.Equal(3.0, mean);
+        Assert.True(udf.Merges > 0);
+    }
+
+    [Fact]
+    public async Task RegisterAggregateUdf_UpdateThrows_QueryFails()
+    {
+        // Arrange
+        _context.RegisterAggregateUdf(new MeanUdf { FailUpdate = true });
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT my_mean(v) FROM (VALUES (1.0)) t(v)");
+            using var collected = await df.CollectAsync();
+        });
+        Assert.Contains("my_mean", ex.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void RegisterAggregateUdf_WithoutStateFields_Throws()
+    {
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.RegisterAggregateUdf(new StatelessUdf()));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private sealed class MeanUdf() : AggregateUdf(
+        "my_mean",
+        [DoubleType.Default],
+        DoubleType.Default,
+        [new Field("sum", DoubleType.Default, nullable: true), new Field("count", Int64Type.Default, nullable: true)],
+        Volatility.Immutable)
+    {
+        private int _merges;
+
+        public bool FailUpdate { get; init; }
+
+        public int Merges => Volatile.Read(ref _merges);
+
+        public override Accumulator CreateAccumulator() => new MeanAccumulator(this);
+
+        private sealed class MeanAccumulator(MeanUdf udf) : Accumulator
+        {
+            private double _sum;
+            private long _count;
+
+            public override void Update(IReadOnlyList<IArrowArray> values, int rowCount)
+            {
+                if (udf.FailUpdate)
+                    throw new InvalidOperationException("Update failed");
+
+                foreach (var value in values[0].AsDouble())
+                {
+                    if (!value.HasValue)
+                        continue;
+                    _sum += value.Value;
+                    _count++;
+                }
+            }
+
+            public override void Merge(IReadOnlyList<IArrowArray> states, int rowCount)
+            {
+                Interlocked.Increment(ref udf._merges);
+                _sum += states[0].AsDouble().Sum() ?? 0;
+                _count += states[1].AsInt64().Sum() ?? 0;
+            }
+
+            public override IReadOnlyList<IArrowArray> GetState()
+            {
+                return
+                [
+                    new DoubleArray.Builder().Append(_sum).Build(),
+                    new Int64Array.Builder().Append(_count).Build()
+                ];
+            }
+
+            public override IArrowArray Evaluate()
+            {
+                var builder = new DoubleArray.Builder();
+                if (_count == 0)
+                    builder.AppendNull();
+                else
+                    builder.Append(_sum / _count);
+                return builder.Build();
+            }
+        }
+    }
+
+    private sealed class StatelessUdf() : AggregateUdf("stateless", [DoubleType.Default], DoubleType.Default, [])
+    {
+        public override Accumulator CreateAccumulator() => throw new NotSupportedException();
+    }
+}
@@ -0,0 +1,56 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class ArrowStreamTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public ArrowStreamTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task ExecuteArrowStreamAsync_ReadsAllBatches()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT value AS id FROM generate_series(1, 5) ORDER BY id");
+
+        // Act
+        using var stream = await df.ExecuteArrowStreamAsync();
+        var ids = new List<long?>();
+        while (await stream.ReadNextRecordBatchAsync() is { } batch)
+        {
+            using (batch)
+                ids.AddRange(batch.Column("id").AsInt64());
+        }
+
+        // Assert
+        Assert.Equal("id", stream.Schema.FieldsList.Single().Name);
+        Assert.Equal([1L, 2L, 3L, 4L, 5L], ids);
+    }
+
+    [Fact]
+    public async Task ExecuteArrowStreamAsync_ExecutionError_FailsRead()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT CAST(x AS INT) AS n FROM (VALUES ('1'), ('not a number')) t(x)");
+
+        // Act
+        using var stream = await df.ExecuteArrowStreamAsync();
+
+        // Assert
+        await Assert.ThrowsAnyAsync<Exception>(async () =>
+        {
+            while (await stream.ReadNextRecordBatchAsync() is { } batch)
+                batch.Dispose();
+        });
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+}
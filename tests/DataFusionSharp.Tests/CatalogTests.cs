@@ -0,0 +1,195 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class CatalogTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public CatalogTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task CreateCatalogAndSchema_TablesCanBeCreatedAndQueried()
+    {
+        // Act
+        _context.CreateCatalog("analytics");
+        _context.CreateSchema("analytics", "staging");
+        await ExecuteAsync("CREATE TABLE analytics.staging.events AS VALUES (1), (2)");
+        using var df = await _context.SqlAsync("SELECT * FROM analytics.staging.events");
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(2UL, count);
+        Assert.Equal(["analytics", "datafusion"], _context.GetCatalogNames());
+        Assert.Equal(["staging"], _context.GetSchemaNames("analytics"));
+    }
+
+    [Fact]
+    public void CreateCatalog_AlreadyExists_Throws()
+    {
+        // Arrange
+        _context.CreateCatalog("analytics");
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.CreateCatalog("analytics"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task DropCatalog_NonEmptyWithoutCascade_Throws()
+    {
+        // Arrange
+        _context.CreateCatalog("analytics");
+        _context.CreateSchema("analytics", "staging");
+        await ExecuteAsync("CREATE TABLE analytics.staging.events AS VALUES (1)");
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.DropCatalog("analytics"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, ex.ErrorCode);
+        Assert.Contains("analytics", _context.GetCatalogNames());
+    }
+
+    [Fact]
+    public async Task DropCatalog_WithCascade_RemovesCatalogAndTables()
+    {
+        // Arrange
+        _context.CreateCatalog("analytics");
+        _context.CreateSchema("analytics", "staging");
+        await ExecuteAsync("CREATE TABLE analytics.staging.events AS VALUES (1)");
+
+        // Act
+        _context.DropCatalog("analytics", cascade: true);
+
+        // Assert
+        Assert.Equal(["datafusion"], _context.GetCatalogNames());
+        await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT * FROM analytics.staging.events");
+        });
+    }
+
+    [Fact]
+    public async Task DropSchema_NonEmptyWithoutCascade_Throws()
+    {
+        // Arrange
+        _context.CreateCatalog("analytics");
+        _context.CreateSchema("analytics", "staging");
+        await ExecuteAsync("CREATE TABLE analytics.staging.events AS VALUES (1)");
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.DropSchema("analytics", "staging"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, ex.ErrorCode);
+
+        _context.DropSchema("analytics", "staging", cascade: true);
+        Assert.Empty(_context.GetSchemaNames("analytics"));
+    }
+
+    [Fact]
+    public void DropCatalogAndSchema_Defaults_Throw()
+    {
+        // Act & Assert
+        var catalogEx = Assert.Throws<DataFusionException>(() => _context.DropCatalog("datafusion", cascade: true));
+        Assert.Equal(DataFusionErrorCode.CatalogError, catalogEx.ErrorCode);
+
+        var schemaEx = Assert.Throws<DataFusionException>(() => _context.DropSchema("datafusion", "public", cascade: true));
+        Assert.Equal(DataFusionErrorCode.CatalogError, schemaEx.ErrorCode);
+    }
+
+    [Fact]
+    public void GetSchemaNames_UnknownCatalog_Throws()
+    {
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.GetSchemaNames("unknown"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task GetTableNames_ReturnsTablesAndViews()
+    {
+        // Arrange
+        await _context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+        await ExecuteAsync("CREATE VIEW customer_names AS SELECT customer_name FROM customers");
+
+        // Act
+        var names = _context.GetTableNames("datafusion", "public");
+
+        // Assert
+        Assert.Equal(["customer_names", "customers"], names);
+    }
+
+    [Fact]
+    public async Task TableExists_ReturnsWhetherTableIsRegistered()
+    {
+        // Arrange
+        await _context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+
+        // Act & Assert
+        Assert.True(_context.TableExists("customers"));
+        Assert.True(_context.TableExists("datafusion.public.customers"));
+        Assert.False(_context.TableExists("orders"));
+        Assert.False(_context.TableExists("a.b.c.d"));
+    }
+
+    [Fact]
+    public async Task GetTableInfoAsync_ReturnsSchemaAndKind()
+    {
+        // Arrange
+        await _context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+        await ExecuteAsync("CREATE VIEW customer_names AS SELECT customer_name FROM customers");
+
+        // Act
+        var table = await _context.GetTableInfoAsync("customers");
+        var view = await _context.GetTableInfoAsync("customer_names");
+
+        // Assert
+        Assert.Equal(TableKind.Base, table.Kind);
+        Assert.Equal(6, table.Schema.FieldsList.Count);
+        Assert.Equal("customer_id", table.Schema.FieldsList[0].Name);
+        Assert.Equal(TableKind.View, view.Kind);
+        Assert.Equal("customer_name", Assert.Single(view.Schema.FieldsList).Name);
+    }
+
+    [Fact]
+    public async Task GetTableInfoAsync_UnknownTable_Throws()
+    {
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _context.GetTableInfoAsync("unknown"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task TableAsync_ReturnsAllRowsOfTable()
+    {
+        // Arrange
+        await _context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+
+        // Act
+        using var df = await _context.TableAsync("datafusion.public.customers");
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+    }
+
+    [Fact]
+    public async Task TableAsync_UnknownTable_Throws()
+    {
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _context.TableAsync("unknown"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, ex.ErrorCode);
+    }
+
+    private async Task ExecuteAsync(string sql)
+    {
+        using var df = await _context.SqlAsync(sql);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+}
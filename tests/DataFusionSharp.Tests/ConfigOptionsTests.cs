@@ -0,0 +1,86 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class ConfigOptionsTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public ConfigOptionsTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task SetOptionAsync_ChangesValueSeenBySql()
+    {
+        // Act
+        await _context.SetOptionAsync("datafusion.catalog.information_schema", "true");
+        await _context.SetOptionAsync("datafusion.execution.batch_size", "2048");
+        using var df = await _context.SqlAsync(
+            "SELECT value FROM information_schema.df_settings WHERE name = 'datafusion.execution.batch_size'");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var values = collected.Batches.SelectMany(b => b.Column("value").AsString()).ToList();
+        Assert.Equal(["2048"], values);
+    }
+
+    [Fact]
+    public async Task SetOptionAsync_UnknownOption_Throws()
+    {
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _context.SetOptionAsync("datafusion.unknown_option", "1"));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SetOptionAsync_InvalidValue_Throws()
+    {
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _context.SetOptionAsync("datafusion.execution.batch_size", "abc"));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task GetOption_ReturnsCurrentValue()
+    {
+        // Arrange
+        await _context.SetOptionAsync("datafusion.execution.batch_size", "2048");
+
+        // Act
+        var entry = _context.GetOption("DATAFUSION.EXECUTION.BATCH_SIZE");
+
+        // Assert
+        Assert.Equal("datafusion.execution.batch_size", entry.Key);
+        Assert.Equal("2048", entry.Value);
+        Assert.NotEmpty(entry.Description);
+    }
+
+    [Fact]
+    public void GetOption_UnknownOption_Throws()
+    {
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.GetOption("datafusion.unknown_option"));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public void GetConfigEntries_ReturnsAllEntries()
+    {
+        // Act
+        var entries = _context.GetConfigEntries();
+
+        // Assert
+        var batchSize = Assert.Single(entries, e => e.Key == "datafusion.execution.batch_size");
+        Assert.Equal("8192", batchSize.Value);
+        var timeZone = Assert.Single(entries, e => e.Key == "datafusion.execution.time_zone");
+        Assert.Null(timeZone.Value);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+}
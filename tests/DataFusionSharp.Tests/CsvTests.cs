@@ -461,4 +461,31 @@ public sealed class CsvTests : FileFormatTests
         Assert.DoesNotContain("customer_id", content, StringComparison.Ordinal);
         Assert.DoesNotContain("customer_name", content, StringComparison.Ordinal);
     }
+
+    [Fact]
+    public async Task ReadCsvAsync_WithMultipleFiles_ReadsCombinedDataWithoutRegisteringTable()
+    {
+        // Arrange
+        using var tempDir = TempDirectory.Create();
+        var file1 = Path.Combine(tempDir.Path, "data1.csv");
+        var file2 = Path.Combine(tempDir.Path, "data2.csv");
+        await File.WriteAllLinesAsync(file1, ["id,name", "1,Alice", "2,Bob"]);
+        await File.WriteAllLinesAsync(file2, ["id,name", "3,Charlie"]);
+
+        // Act
+        using var df = await Context.ReadCsvAsync([file1, file2]);
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var names = collected.Batches.SelectMany(b => b.Column("name").AsString()).Order(StringComparer.Ordinal).ToList();
+        Assert.Equal(["Alice", "Bob", "Charlie"], names);
+        Assert.False(Context.TableExists("data1"));
+    }
+
+    [Fact]
+    public async Task ReadCsvAsync_MissingFile_Throws()
+    {
+        // Act & Assert
+        await Assert.ThrowsAsync<DataFusionException>(() => Context.ReadCsvAsync(["/nonexistent/file.csv"]));
+    }
 }
@@ -0,0 +1,82 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class DescribeTests : IDisposable
+{
+    private const string Query = "SELECT * FROM (VALUES (1, 'a'), (2, 'b'), (3, 'b'), (4, NULL)) t(a, b)";
+
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public DescribeTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task DescribeAsync_ReturnsSummaryStatistics()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        using var described = await df.DescribeAsync();
+
+        // Assert
+        var stats = Rows(described);
+        Assert.Equal(["count", "null_count", "mean", "std", "min", "max", "median"], stats.Select(r => r.Name));
+        Assert.Equal(2.5, stats.Single(r => r.Name == "mean").A);
+        Assert.Equal("1", stats.Single(r => r.Name == "null_count").B);
+        Assert.Equal("b", stats.Single(r => r.Name == "max").B);
+    }
+
+    [Fact]
+    public async Task ProfileAsync_AddsApproximateStatistics()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        using var profiled = await df.ProfileAsync([0.5]);
+
+        // Assert
+        var stats = Rows(profiled);
+        Assert.Equal(["approx_distinct", "approx_percentile_0.5"], stats.Select(r => r.Name).Skip(7));
+        Assert.Equal(4.0, stats.Single(r => r.Name == "approx_distinct").A);
+        Assert.Equal("2", stats.Single(r => r.Name == "approx_distinct").B);
+        Assert.InRange(stats.Single(r => r.Name == "approx_percentile_0.5").A!.Value, 1.0, 4.0);
+        Assert.Null(stats.Single(r => r.Name == "approx_percentile_0.5").B);
+    }
+
+    [Fact]
+    public async Task ProfileAsync_QuantileOutOfRange_Throws()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => df.ProfileAsync([1.5]));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static List<(string Name, double? A, string? B)> Rows(DataFrameCollectedResult result)
+    {
+        var rows = new List<(string Name, double? A, string? B)>();
+        foreach (var batch in result.Batches)
+        {
+            var names = batch.Column("describe").AsString().ToList();
+            var a = batch.Column("a").AsDouble().ToList();
+            var b = batch.Column("b").AsString().ToList();
+            for (var i = 0; i < names.Count; i++)
+                rows.Add((names[i]!, a[i], b[i]));
+        }
+
+        return rows;
+    }
+}
@@ -0,0 +1,72 @@
+using System.Text.Json;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class ExplainTests : IDisposable
+{
+    private const string Query = "SELECT a FROM (VALUES (1), (2), (3)) t(a) WHERE a > 1";
+
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public ExplainTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task ExplainAsync_Indent_ReturnsTextPlans()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        var result = await df.ExplainAsync();
+
+        // Assert
+        Assert.Equal(["logical_plan", "physical_plan"], result.Plans.Select(p => p.PlanType));
+        var physical = result.Plans.Single(p => p.PlanType == "physical_plan");
+        Assert.Equal(Proto.ExplainPlan.PlanOneofCase.Text, physical.PlanCase);
+        Assert.Contains("FilterExec", physical.Text, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task ExplainAsync_StructuredAnalyze_ReturnsMetrics()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        var result = await df.ExplainAsync(ExplainFormat.Structured, analyze: true);
+
+        // Assert
+        var physical = result.Plans.Single(p => p.PlanType == "physical_plan");
+        Assert.Equal(Proto.ExplainPlan.PlanOneofCase.Root, physical.PlanCase);
+        var filter = physical.Root.Children.Single();
+        Assert.Equal("FilterExec", filter.Name);
+        Assert.Equal("2", filter.Metrics["output_rows"]);
+    }
+
+    [Fact]
+    public async Task ExplainJsonAsync_ReturnsPlanTrees()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        var json = await df.ExplainJsonAsync();
+
+        // Assert
+        using var document = JsonDocument.Parse(json);
+        var plans = document.RootElement.GetProperty("plans");
+        Assert.Equal("logical_plan", plans[0].GetProperty("planType").GetString());
+        Assert.Equal("SubqueryAlias", plans[0].GetProperty("root").GetProperty("name").GetString());
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+}
@@ -0,0 +1,109 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class JoinTests : IDisposable
+{
+    private const string CustomersQuery = "SELECT * FROM (VALUES (1, 'a'), (2, 'b'), (3, 'c')) l(id, name)";
+    private const string OrdersQuery = "SELECT * FROM (VALUES (1, 10), (3, 30), (3, 31)) r(customer_id, amount)";
+
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public JoinTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task Join_Inner_WithFilter_ReturnsMatchingPairs()
+    {
+        // Arrange
+        using var customers = await _context.SqlAsync(CustomersQuery);
+        using var orders = await _context.SqlAsync(OrdersQuery);
+
+        // Act
+        using var joined = customers.Join(orders, JoinType.Inner, ["id"], ["customer_id"], Binary(Col("amount"), "Gt", Lit(30)));
+        using var collected = await joined.CollectAsync();
+
+        // Assert
+        Assert.Equal(["id", "name", "customer_id", "amount"], collected.Schema.FieldsList.Select(f => f.Name));
+        Assert.Equal([31L], collected.Batches.SelectMany(b => b.Column("amount").AsInt64()));
+    }
+
+    [Fact]
+    public async Task Join_LeftSemi_ReturnsLeftRowsWithMatch()
+    {
+        // Arrange
+        using var customers = await _context.SqlAsync(CustomersQuery);
+        using var orders = await _context.SqlAsync(OrdersQuery);
+
+        // Act
+        using var joined = customers.Join(orders, JoinType.LeftSemi, ["id"], ["customer_id"]);
+        using var sorted = joined.Sort([new Proto.SortExprNode { Expr = Col("id"), Asc = true }]);
+        using var collected = await sorted.CollectAsync();
+
+        // Assert
+        Assert.Equal(["id", "name"], collected.Schema.FieldsList.Select(f => f.Name));
+        Assert.Equal(["a", "c"], collected.Batches.SelectMany(b => b.Column("name").AsString()));
+    }
+
+    [Fact]
+    public async Task Join_NonBooleanFilter_Throws()
+    {
+        // Arrange
+        using var customers = await _context.SqlAsync(CustomersQuery);
+        using var orders = await _context.SqlAsync(OrdersQuery);
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => customers.Join(orders, JoinType.Inner, ["id"], ["customer_id"], Col("amount")));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CrossJoin_ReturnsEveryCombination()
+    {
+        // Arrange
+        using var customers = await _context.SqlAsync(CustomersQuery);
+        using var orders = await _context.SqlAsync(OrdersQuery);
+
+        // Act
+        using var joined = customers.CrossJoin(orders);
+        var count = await joined.CountAsync();
+
+        // Assert
+        Assert.Equal(9UL, count);
+    }
+
+    [Fact]
+    public async Task Union_Intersect_Except_CombineRows()
+    {
+        // Arrange
+        using var customers = await _context.SqlAsync(CustomersQuery);
+        using var other = await _context.SqlAsync("SELECT * FROM (VALUES (2, 'b'), (4, 'd')) o(id, name)");
+
+        // Act
+        using var union = customers.Union(other);
+        using var unionDistinct = customers.Union(other, distinct: true);
+        using var intersect = customers.Intersect(other);
+        using var except = customers.Except(other);
+
+        // Assert
+        Assert.Equal(5UL, await union.CountAsync());
+        Assert.Equal(4UL, await unionDistinct.CountAsync());
+        Assert.Equal(1UL, await intersect.CountAsync());
+        Assert.Equal(2UL, await except.CountAsync());
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static Proto.LogicalExprNode Col(string name) => new() { Column = new Proto.Column { Name = name } };
+
+    private static Proto.LogicalExprNode Lit(long value) => new() { Literal = new Proto.ScalarValue { Int64Value = value } };
+
+    private static Proto.LogicalExprNode Binary(Proto.LogicalExprNode left, string op, Proto.LogicalExprNode right) =>
+        new() { BinaryExpr = new Proto.BinaryExprNode { Operands = { left, right }, Op = op } };
+}
@@ -177,4 +177,16 @@ public sealed class JsonTests : FileFormatTests
         Assert.Contains("customer_id", lines[0], StringComparison.Ordinal);
         Assert.Contains("customer_id", lines[^1], StringComparison.Ordinal);
     }
+
+    [Fact]
+    public async Task ReadJsonAsync_ReadsFileWithoutRegisteringTable()
+    {
+        // Act
+        using var df = await Context.ReadJsonAsync([DataSet.CustomersJsonPath]);
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+        Assert.False(Context.TableExists("customers"));
+    }
 }
@@ -0,0 +1,77 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class MetricsTests : IDisposable
+{
+    private const string Query = "SELECT a FROM (VALUES (1), (2), (3)) t(a) WHERE a > 1";
+
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public MetricsTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task GetMetrics_NotCollected_ReturnsNull()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        var metrics = df.GetMetrics();
+
+        // Assert
+        Assert.Null(metrics);
+    }
+
+    [Fact]
+    public async Task GetMetrics_AfterCollect_ReturnsOperatorTree()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+        using var collected = await df.CollectAsync();
+
+        // Act
+        var metrics = df.GetMetrics();
+
+        // Assert
+        Assert.NotNull(metrics);
+        Assert.Equal(2UL, OutputRows(metrics));
+        var filter = metrics.Children.Single();
+        Assert.Equal("FilterExec", filter.Name);
+        Assert.Equal(2UL, OutputRows(filter));
+    }
+
+    [Fact]
+    public async Task DataFrameStream_GetMetrics_AfterDrain_ReturnsOutputRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+        using var stream = await df.ExecuteStreamAsync();
+        var rows = 0;
+        await foreach (var batch in stream)
+            rows += batch.Length;
+
+        // Act
+        var metrics = stream.GetMetrics();
+
+        // Assert
+        Assert.Equal(2, rows);
+        Assert.Equal((ulong)rows, OutputRows(metrics));
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static ulong OutputRows(Proto.OperatorMetrics metrics)
+    {
+        var metric = metrics.Metrics.Single(m => m.Name == "output_rows");
+        Assert.Equal(Proto.MetricKind.Count, metric.Kind);
+        return metric.Value;
+    }
+}
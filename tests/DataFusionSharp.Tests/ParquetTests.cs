@@ -146,4 +146,25 @@ public sealed class ParquetTests : FileFormatTests
         var count = await readBackDf.CountAsync();
         Assert.Equal(500UL, count);
     }
+
+    [Fact]
+    public async Task ReadParquetAsync_WithSchema_ProjectsOnlySpecifiedColumns()
+    {
+        // Arrange
+        var options = new ParquetReadOptions
+        {
+            Schema = new Schema.Builder()
+                .Field(f => f.Name("customer_id").DataType(Int64Type.Default).Nullable(true))
+                .Build()
+        };
+
+        // Act
+        using var df = await Context.ReadParquetAsync([DataSet.CustomersParquetPath], options);
+        var schema = df.GetSchema();
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+        Assert.Equal("customer_id", Assert.Single(schema.FieldsList).Name);
+    }
 }
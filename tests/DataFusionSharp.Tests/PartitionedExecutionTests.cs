@@ -0,0 +1,82 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class PartitionedExecutionTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public PartitionedExecutionTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+        RegisterNumbers(_context);
+    }
+
+    [Fact]
+    public async Task CollectPartitionedAsync_KeepsBatchesGroupedByPartition()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT id FROM numbers");
+
+        // Act
+        using var collected = await df.CollectPartitionedAsync();
+
+        // Assert
+        var partitions = collected.Partitions
+            .Select(p => p.SelectMany(b => b.Column("id").AsInt64()).ToList())
+            .ToList();
+        Assert.Equal(3, partitions.Count);
+        Assert.Equal([1L, 2L], partitions[0]);
+        Assert.Equal([3L, 4L], partitions[1]);
+        Assert.Equal([5L], partitions[2]);
+        Assert.NotNull(df.GetMetrics());
+    }
+
+    [Fact]
+    public async Task ExecuteStreamPartitionedAsync_StreamsCanBeConsumedConcurrently()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT id FROM numbers");
+
+        // Act
+        var streams = await df.ExecuteStreamPartitionedAsync();
+        List<long?>[] partitions;
+        try
+        {
+            partitions = await Task.WhenAll(streams.Select(stream => Task.Run(async () =>
+            {
+                var ids = new List<long?>();
+                await foreach (var batch in stream)
+                    ids.AddRange(batch.Column("id").AsInt64());
+                return ids;
+            })));
+        }
+        finally
+        {
+            foreach (var stream in streams)
+                stream.Dispose();
+        }
+
+        // Assert
+        Assert.Equal(3, partitions.Length);
+        Assert.Equal([1L, 2L, 3L, 4L, 5L], partitions.SelectMany(p => p).Order());
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static void RegisterNumbers(SessionContext context)
+    {
+        var schema = new Schema([new Field("id", Int64Type.Default, nullable: false)], []);
+        using var first = new RecordBatch(schema, [new Int64Array.Builder().AppendRange([1, 2]).Build()], 2);
+        using var second = new RecordBatch(schema, [new Int64Array.Builder().AppendRange([3, 4]).Build()], 2);
+        using var third = new RecordBatch(schema, [new Int64Array.Builder().AppendRange([5]).Build()], 1);
+        context.RegisterBatches("numbers", schema, [first, second, third], partitionCount: 3);
+    }
+}
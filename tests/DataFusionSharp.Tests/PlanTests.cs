@@ -0,0 +1,137 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class PlanTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+    private readonly SessionContext _workerContext;
+
+    public PlanTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+        _workerContext = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task FromLogicalPlanAsync_ResolvesTablesInOtherSession()
+    {
+        // Arrange
+        RegisterNumbers(_context);
+        RegisterNumbers(_workerContext);
+        using var df = await _context.SqlAsync("SELECT id FROM numbers WHERE id > 2 ORDER BY id");
+
+        // Act
+        var plan = df.ToLogicalPlanBytes();
+        using var imported = await _workerContext.FromLogicalPlanAsync(plan);
+        using var collected = await imported.CollectAsync();
+
+        // Assert
+        var ids = collected.Batches.SelectMany(b => b.Column("id").AsInt64()).ToList();
+        Assert.Equal([3L, 4L, 5L], ids);
+    }
+
+    [Fact]
+    public async Task FromLogicalPlanAsync_ListingTable_DoesNotNeedRegistration()
+    {
+        // Arrange
+        await _context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+        using var df = await _context.SqlAsync("SELECT * FROM customers");
+
+        // Act
+        var plan = df.ToLogicalPlanBytes();
+        using var imported = await _workerContext.FromLogicalPlanAsync(plan);
+        var count = await imported.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+    }
+
+    [Fact]
+    public async Task FromLogicalPlanAsync_UnknownTable_Throws()
+    {
+        // Arrange
+        RegisterNumbers(_context);
+        using var df = await _context.SqlAsync("SELECT id FROM numbers");
+        var plan = df.ToLogicalPlanBytes();
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _workerContext.FromLogicalPlanAsync(plan));
+        Assert.Equal(DataFusionErrorCode.DataFrameError, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task FromLogicalPlanAsync_InvalidBytes_Throws()
+    {
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _workerContext.FromLogicalPlanAsync(new byte[] { 0xFF, 0xFF }));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task ExecutePhysicalPlanPartitionAsync_AllPartitions_ReturnAllRows()
+    {
+        // Arrange
+        RegisterNumbers(_context);
+        RegisterNumbers(_workerContext);
+        using var df = await _context.SqlAsync("SELECT id FROM numbers WHERE id > 2");
+
+        // Act
+        var plan = await df.ToPhysicalPlanBytesAsync();
+        var ids = new List<long?>();
+        for (var partition = 0; partition < plan.PartitionCount; partition++)
+        {
+            using var stream = await _workerContext.ExecutePhysicalPlanPartitionAsync(plan.Plan, partition);
+            await foreach (var batch in stream)
+                ids.AddRange(batch.Column("id").AsInt64());
+        }
+
+        // Assert
+        Assert.True(plan.PartitionCount > 0);
+        Assert.Equal([3L, 4L, 5L], ids.Order());
+    }
+
+    [Fact]
+    public async Task ExecutePhysicalPlanPartitionAsync_PartitionOutOfRange_Throws()
+    {
+        // Arrange
+        RegisterNumbers(_context);
+        RegisterNumbers(_workerContext);
+        using var df = await _context.SqlAsync("SELECT id FROM numbers");
+        var plan = await df.ToPhysicalPlanBytesAsync();
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _workerContext.ExecutePhysicalPlanPartitionAsync(plan.Plan, plan.PartitionCount));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task ExecutePhysicalPlanPartitionAsync_UnknownTable_Throws()
+    {
+        // Arrange
+        RegisterNumbers(_context);
+        using var df = await _context.SqlAsync("SELECT id FROM numbers");
+        var plan = await df.ToPhysicalPlanBytesAsync();
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _workerContext.ExecutePhysicalPlanPartitionAsync(plan.Plan, 0));
+        Assert.Equal(DataFusionErrorCode.DataFrameError, ex.ErrorCode);
+    }
+
+    public void Dispose()
+    {
+        _workerContext.Dispose();
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static void RegisterNumbers(SessionContext context)
+    {
+        var schema = new Schema([new Field("id", Int64Type.Default, nullable: false)], []);
+        using var batch = new RecordBatch(schema, [new Int64Array.Builder().AppendRange([1, 2, 3, 4, 5]).Build()], 5);
+        context.RegisterBatches("numbers", schema, [batch], partitionCount: 2);
+    }
+}
@@ -1,5 +1,7 @@
 using Apache.Arrow;
+using Apache.Arrow.C;
 using Apache.Arrow.Types;
+using DataFusionSharp.Interop;
 
 namespace DataFusionSharp.Tests;
 
@@ -104,12 +106,159 @@ public sealed class RegisterBatchTests : IDisposable
         Assert.Equal("Order completed successfully", descriptions.First());
     }
 
+    [Fact]
+    public async Task RegisterBatches_WithMultipleBatchesAndPartitions_RegistersAllRows()
+    {
+        // Arrange
+        using var batch1 = CreateRecordBatch();
+        using var batch2 = CreateRecordBatch();
+        using var batch3 = CreateRecordBatch();
+
+        // Act
+        _context.RegisterBatches("test", batch1.Schema, [batch1, batch2, batch3], partitionCount: 2);
+        using var df = await _context.SqlAsync("SELECT name FROM test ORDER BY name");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var names = collected.Batches.SelectMany(b => b.Column("name").AsString()).ToList();
+        Assert.Equal(["Alice", "Alice", "Alice", "Bob", "Bob", "Bob"], names);
+    }
+
+    [Fact]
+    public async Task RegisterBatches_WithNoBatches_RegistersEmptyTable()
+    {
+        // Arrange
+        using var batch = CreateRecordBatch();
+
+        // Act
+        _context.RegisterBatches("test", batch.Schema, []);
+        using var df = await _context.SqlAsync("SELECT * FROM test");
+        var count = await df.CountAsync();
+        var schema = df.GetSchema();
+
+        // Assert
+        Assert.Equal(0UL, count);
+        Assert.Equal(["id", "name"], schema.FieldsList.Select(f => f.Name));
+    }
+
+    [Fact]
+    public void RegisterBatches_NegativePartitionCount_Throws()
+    {
+        // Arrange
+        using var batch = CreateRecordBatch();
+
+        // Act & Assert
+        Assert.Throws<ArgumentOutOfRangeException>(() => _context.RegisterBatches("test", batch.Schema, [batch], -1));
+    }
+
+    [Fact]
+    public async Task ImportBatches_CreatesQueryableTableThatOutlivesBatches()
+    {
+        // Arrange
+        var batch1 = CreateRecordBatch();
+        var batch2 = CreateRecordBatch();
+        var schema = batch1.Schema;
+
+        // Act
+        _context.ImportBatches("test", schema, [batch1, batch2], partitionCount: 2);
+        batch1.Dispose();
+        batch2.Dispose();
+        using var df = await _context.SqlAsync("SELECT id, name FROM test ORDER BY id, name");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var names = collected.Batches.SelectMany(b => b.Column("name").AsString()).ToList();
+        Assert.Equal(["Alice", "Alice", "Bob", "Bob"], names);
+    }
+
+    [Fact]
+    public async Task ImportBatches_WithNoBatches_RegistersEmptyTable()
+    {
+        // Arrange
+        using var batch = CreateRecordBatch();
+
+        // Act
+        _context.ImportBatches("test", batch.Schema, []);
+        using var df = await _context.SqlAsync("SELECT * FROM test");
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(0UL, count);
+    }
+
+    [Fact]
+    public void ImportBatches_UnknownCatalog_Throws()
+    {
+        // Arrange
+        using var batch = CreateRecordBatch();
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.ImportBatches("unknown.public.test", batch.Schema, [batch]));
+        Assert.Equal(DataFusionErrorCode.TableRegistrationFailed, ex.ErrorCode);
+    }
+
+    [Fact]
+    public void RegisterFfiBatches_NonStructArray_ThrowsInvalidArgument()
+    {
+        // Arrange
+        using var batch = CreateRecordBatch();
+        using var array = new Int64Array.Builder().Append(1).Append(2).Build();
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => RegisterFfiArray(batch.Schema, array));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public void RegisterFfiBatches_StructArrayWithMissingColumn_ThrowsInvalidArgument()
+    {
+        // Arrange
+        using var batch = CreateRecordBatch();
+        var idArray = new Int64Array.Builder().Append(1).Append(2).Build();
+        using var array = new StructArray(new StructType([batch.Schema.GetFieldByName("id")]), 2, [idArray], ArrowBuffer.Empty);
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => RegisterFfiArray(batch.Schema, array));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
     public void Dispose()
     {
         _context.Dispose();
         _runtime.Dispose();
     }
     
+    /// <summary>
+    /// Hands a single array to the native import as is, bypassing the record batch export of <see cref="SessionContext.ImportBatches"/>.
+    /// </summary>
+    private unsafe void RegisterFfiArray(Schema schema, IArrowArray array)
+    {
+        var cSchema = CArrowSchema.Create();
+        var cArray = CArrowArray.Create();
+        try
+        {
+            CArrowSchemaExporter.ExportSchema(schema, cSchema);
+            CArrowArrayExporter.ExportArray(array, cArray);
+
+            var op = new SyncVoidOperation();
+            var result = NativeMethods.ContextRegisterFfiBatches(
+                _context.Handle,
+                "test",
+                cSchema,
+                cArray,
+                1,
+                0,
+                &GenericCallbacks.CallbackForVoidSync,
+                op.GetHandle());
+            op.EnsureNativeCall(result, "Failed to start record batches import.");
+        }
+        finally
+        {
+            CArrowArray.Free(cArray);
+            CArrowSchema.Free(cSchema);
+        }
+    }
+
     private static RecordBatch CreateRecordBatch()
     {
         var idArray = new Int64Array.Builder().Append(1).Append(2).Build();
@@ -0,0 +1,140 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class ScalarUdfTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public ScalarUdfTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task RegisterScalarUdf_QueryCallsFunction()
+    {
+        // Arrange
+        var udf = new TwiceUdf();
+        _context.RegisterScalarUdf(udf);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT twice(v) AS d FROM (VALUES (1), (NULL), (3)) t(v) ORDER BY d NULLS LAST");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var values = collected.Batches.SelectMany(b => b.Column("d").AsInt64()).ToList();
+        Assert.Equal([2L, 6L, null], values);
+        Assert.True(udf.Calls > 0);
+    }
+
+    [Fact]
+    public async Task RegisterScalarUdf_WithStringArguments_ReturnsStrings()
+    {
+        // Arrange
+        _context.RegisterScalarUdf(new GreetUdf());
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT greet(name, '!') AS g FROM (VALUES ('alice'), ('bob')) t(name) ORDER BY g");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var values = collected.Batches.SelectMany(b => b.Column("g").AsString()).ToList();
+        Assert.Equal(["hello alice!", "hello bob!"], values);
+    }
+
+    [Fact]
+    public async Task RegisterScalarUdf_InvokeThrows_QueryFails()
+    {
+        // Arrange
+        _context.RegisterScalarUdf(new TwiceUdf { Fail = true });
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT twice(v) FROM (VALUES (1)) t(v)");
+            using var collected = await df.CollectAsync();
+        });
+        Assert.Contains("twice", ex.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task RegisterScalarUdf_ReturnsInvalidArray_QueryFails()
+    {
+        // Arrange
+        _context.RegisterScalarUdf(new InvalidUtf8Udf());
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT invalid_utf8(v) FROM (VALUES (1), (2)) t(v)");
+            using var collected = await df.CollectAsync();
+        });
+        Assert.Contains("invalid Utf8 array", ex.Message, StringComparison.Ordinal);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private sealed class TwiceUdf() : ScalarUdf("twice", [Int64Type.Default], Int64Type.Default, Volatility.Immutable)
+    {
+        private int _calls;
+
+        public bool Fail { get; init; }
+
+        public int Calls => Volatile.Read(ref _calls);
+
+        public override IArrowArray Invoke(IReadOnlyList<IArrowArray> arguments, int rowCount)
+        {
+            Interlocked.Increment(ref _calls);
+            if (Fail)
+                throw new InvalidOperationException("Invoke failed");
+
+            var builder = new Int64Array.Builder();
+            foreach (var value in arguments[0].AsInt64())
+            {
+                if (value.HasValue)
+                    builder.Append(value.Value * 2);
+                else
+                    builder.AppendNull();
+            }
+            return builder.Build();
+        }
+    }
+
+    private sealed class GreetUdf() : ScalarUdf("greet", [StringType.Default, StringType.Default], StringType.Default)
+    {
+        public override IArrowArray Invoke(IReadOnlyList<IArrowArray> arguments, int rowCount)
+        {
+            var names = (StringArray)arguments[0];
+            var suffixes = (StringArray)arguments[1];
+            var builder = new StringArray.Builder();
+            for (var i = 0; i < rowCount; i++)
+                builder.Append($"hello {names.GetString(i)}{suffixes.GetString(i)}");
+            return builder.Build();
+        }
+    }
+
+    private sealed class InvalidUtf8Udf() : ScalarUdf("invalid_utf8", [Int64Type.Default], StringType.Default)
+    {
+        public override IArrowArray Invoke(IReadOnlyList<IArrowArray> arguments, int rowCount)
+        {
+            var offsets = new ArrowBuffer.Builder<int>();
+            var data = new ArrowBuffer.Builder<byte>();
+            for (var i = 0; i < rowCount; i++)
+            {
+                offsets.Append(i);
+                data.Append(0xFF);
+            }
+            offsets.Append(rowCount);
+            return new StringArray(rowCount, offsets.Build(), data.Build(), ArrowBuffer.Empty);
+        }
+    }
+}
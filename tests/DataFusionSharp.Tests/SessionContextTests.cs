@@ -2,6 +2,9 @@ namespace DataFusionSharp.Tests;
 
 public sealed class SessionContextTests : IDisposable
 {
+    private const string SortedSeriesSql =
+        "SELECT v, repeat('x', 100) AS s FROM generate_series(1, 1000000) t(v) ORDER BY v DESC";
+
     private readonly DataFusionRuntime _runtime = DataFusionRuntime.Create();
 
     [Fact]
@@ -14,6 +17,112 @@ public sealed class SessionContextTests : IDisposable
         Assert.NotNull(context);
     }
 
+    [Fact]
+    public async Task CreateSessionContext_WithSessionConfig_AppliesSettings()
+    {
+        // Arrange
+        var config = new SessionConfig
+        {
+            TargetPartitions = 3,
+            InformationSchema = true,
+            Options = new Dictionary<string, string> { ["datafusion.execution.batch_size"] = "1024" }
+        };
+
+        // Act
+        using var context = _runtime.CreateSessionContext(config);
+        using var df = await context.SqlAsync(
+            "SELECT name, value FROM information_schema.df_settings " +
+            "WHERE name IN ('datafusion.execution.target_partitions', 'datafusion.execution.batch_size') ORDER BY name");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var values = collected.Batches.SelectMany(b => b.Column("value").AsString()).ToList();
+        Assert.Equal(["1024", "3"], values);
+    }
+
+    [Fact]
+    public async Task CreateSessionContext_WithDefaultCatalogAndSchema_ResolvesBareTableNames()
+    {
+        // Arrange
+        var config = new SessionConfig { DefaultCatalog = "warehouse", DefaultSchema = "sales" };
+        using var context = _runtime.CreateSessionContext(config);
+        await context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+
+        // Act
+        using var df = await context.SqlAsync("SELECT * FROM warehouse.sales.customers");
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+    }
+
+    [Fact]
+    public void CreateSessionContext_WithInvalidSessionConfig_Throws()
+    {
+        // Arrange
+        var config = new SessionConfig { TargetPartitions = 0 };
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _runtime.CreateSessionContext(config));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CreateSessionContext_WithMemoryLimitAndSpillingDisabled_FailsWithResourcesExhausted()
+    {
+        // Arrange
+        var runtimeEnvConfig = new RuntimeEnvConfig
+        {
+            MemoryPoolType = MemoryPoolType.Greedy,
+            MemoryLimit = 1024 * 1024,
+            DiskManagerMode = DiskManagerMode.Disabled
+        };
+        using var context = _runtime.CreateSessionContext(runtimeEnvConfig: runtimeEnvConfig);
+        using var df = await context.SqlAsync(SortedSeriesSql);
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var collected = await df.CollectAsync();
+        });
+        Assert.Equal(DataFusionErrorCode.ResourcesExhausted, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CreateSessionContext_WithMemoryLimitAndTempDirectories_SpillsToDisk()
+    {
+        // Arrange
+        using var tempDir = TempDirectory.Create();
+        var runtimeEnvConfig = new RuntimeEnvConfig
+        {
+            MemoryPoolType = MemoryPoolType.FairSpill,
+            MemoryLimit = 32 * 1024 * 1024,
+            DiskManagerMode = DiskManagerMode.Directories,
+            TempDirectories = [tempDir.Path]
+        };
+        using var context = _runtime.CreateSessionContext(runtimeEnvConfig: runtimeEnvConfig);
+        using var df = await context.SqlAsync(SortedSeriesSql);
+
+        // Act
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(1_000_000, collected.Batches.Sum(b => b.Length));
+        Assert.Equal(1_000_000L, collected.Batches[0].Column("v").AsInt64().First());
+        Assert.NotEmpty(Directory.GetDirectories(tempDir.Path));
+    }
+
+    [Fact]
+    public void CreateSessionContext_WithMemoryLimitForUnboundedPool_Throws()
+    {
+        // Arrange
+        var runtimeEnvConfig = new RuntimeEnvConfig { MemoryLimit = 1024 };
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _runtime.CreateSessionContext(runtimeEnvConfig: runtimeEnvConfig));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, ex.ErrorCode);
+    }
+
     [Fact]
     public async Task SqlAsync_ReturnsDataFrame()
     {
@@ -0,0 +1,147 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class StreamingTableTests : IDisposable
+{
+    private static readonly Schema EventSchema = new([new Field("id", Int64Type.Default, nullable: false)], []);
+
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public StreamingTableTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task PushAsync_ThenFinish_QueryReturnsPushedRows()
+    {
+        // Arrange
+        using var table = _context.RegisterStreamingTable("events", EventSchema);
+        using var batch1 = CreateBatch(1, 2);
+        using var batch2 = CreateBatch(3);
+
+        // Act
+        await table.PushAsync(batch1);
+        await table.PushAsync(batch2);
+        table.Finish();
+        using var df = await _context.SqlAsync("SELECT id FROM events ORDER BY id");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var ids = collected.Batches.SelectMany(b => ((Int64Array)b.Column("id")).Values.ToArray()).ToList();
+        Assert.Equal([1L, 2L, 3L], ids);
+    }
+
+    [Fact]
+    public async Task PushAsync_BufferFull_WaitsUntilQueryConsumesBatches()
+    {
+        // Arrange
+        using var table = _context.RegisterStreamingTable("events", EventSchema, capacity: 1);
+        using var batch1 = CreateBatch(1);
+        using var batch2 = CreateBatch(2);
+        await table.PushAsync(batch1);
+
+        // Act
+        var blockedPush = table.PushAsync(batch2);
+        var completedBeforeQuery = blockedPush.IsCompleted;
+
+        using var df = await _context.SqlAsync("SELECT id FROM events");
+        var collectTask = df.CollectAsync();
+        await blockedPush;
+        table.Finish();
+        using var collected = await collectTask;
+
+        // Assert
+        Assert.False(completedBeforeQuery);
+        Assert.Equal(2, collected.Batches.Sum(b => b.Length));
+    }
+
+    [Fact]
+    public async Task PushAsync_AfterFinish_Throws()
+    {
+        // Arrange
+        using var table = _context.RegisterStreamingTable("events", EventSchema);
+        using var batch = CreateBatch(1);
+        table.Finish();
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => table.PushAsync(batch));
+        Assert.Equal(DataFusionErrorCode.StreamClosed, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task Finish_WhilePushWaitsForQuery_PushThrowsAndKeptBatchesAreDelivered()
+    {
+        // Arrange
+        using var table = _context.RegisterStreamingTable("events", EventSchema, capacity: 1);
+        using var batch1 = CreateBatch(1);
+        using var batch2 = CreateBatch(2);
+        await table.PushAsync(batch1);
+        var blockedPush = table.PushAsync(batch2);
+
+        // Act
+        table.Finish();
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => blockedPush);
+        using var df = await _context.SqlAsync("SELECT id FROM events");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(DataFusionErrorCode.StreamClosed, ex.ErrorCode);
+        Assert.Equal([1L], collected.Batches.SelectMany(b => ((Int64Array)b.Column("id")).Values.ToArray()));
+    }
+
+    [Fact]
+    public async Task Dispose_WithoutFinish_RunningQueryCompletes()
+    {
+        // Arrange
+        using var table = _context.RegisterStreamingTable("events", EventSchema);
+        using var batch = CreateBatch(1);
+        await table.PushAsync(batch);
+        using var df = await _context.SqlAsync("SELECT id FROM events");
+        var collectTask = df.CollectAsync();
+
+        // Act
+        table.Dispose();
+        using var collected = await collectTask.WaitAsync(TimeSpan.FromSeconds(30));
+
+        // Assert
+        Assert.Equal([1L], collected.Batches.SelectMany(b => ((Int64Array)b.Column("id")).Values.ToArray()));
+    }
+
+    [Fact]
+    public async Task PushAsync_EveryBatchIsConsumedByOneQuery()
+    {
+        // Arrange
+        using var table = _context.RegisterStreamingTable("events", EventSchema);
+        using var batch1 = CreateBatch(1);
+        using var batch2 = CreateBatch(2);
+        await table.PushAsync(batch1);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT id FROM events LIMIT 1");
+        using var first = await df.CollectAsync();
+        await table.PushAsync(batch2);
+        table.Finish();
+        using var second = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal([1L], first.Batches.SelectMany(b => ((Int64Array)b.Column("id")).Values.ToArray()));
+        Assert.Equal([2L], second.Batches.SelectMany(b => ((Int64Array)b.Column("id")).Values.ToArray()));
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static RecordBatch CreateBatch(params long[] ids)
+    {
+        var idArray = new Int64Array.Builder().AppendRange(ids).Build();
+        return new RecordBatch(EventSchema, [idArray], ids.Length);
+    }
+}
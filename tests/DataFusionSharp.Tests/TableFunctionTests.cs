@@ -0,0 +1,113 @@
+using Apache.Arrow;
+using Apache.Arrow.Ipc;
+using Apache.Arrow.Types;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class TableFunctionTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public TableFunctionTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task RegisterTableFunction_QueryReadsRowsFromCall()
+    {
+        // Arrange
+        _context.RegisterTableFunction(new RangeFunction());
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT n FROM my_range(1 + 1, 3) ORDER BY n");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var values = collected.Batches.SelectMany(b => b.Column("n").AsInt64()).ToList();
+        Assert.Equal([2L, 3L, 4L], values);
+    }
+
+    [Fact]
+    public async Task RegisterTableFunction_JoinScansCallResultTwice()
+    {
+        // Arrange
+        var function = new RangeFunction();
+        _context.RegisterTableFunction(function);
+
+        // Act
+        using var df = await _context.SqlAsync("WITH r AS (SELECT n FROM my_range(1, 2)) SELECT a.n AS a, b.n AS b FROM r a CROSS JOIN r b ORDER BY a, b");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(4, collected.Batches.Sum(b => b.Length));
+        Assert.Equal([1L, 1L, 2L, 2L], collected.Batches.SelectMany(b => b.Column("a").AsInt64()));
+    }
+
+    [Fact]
+    public async Task RegisterTableFunction_NonConstantArgument_Throws()
+    {
+        // Arrange
+        _context.RegisterTableFunction(new RangeFunction());
+
+        // Act & Assert
+        await Assert.ThrowsAsync<DataFusionException>(() => _context.SqlAsync("SELECT * FROM my_range(random(), 1)"));
+    }
+
+    [Fact]
+    public async Task RegisterTableFunction_CallThrows_QueryFails()
+    {
+        // Arrange
+        _context.RegisterTableFunction(new RangeFunction { Fail = true });
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(() => _context.SqlAsync("SELECT * FROM my_range(1, 1)"));
+        Assert.Contains("my_range", ex.Message, StringComparison.Ordinal);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private sealed class RangeFunction() : TableFunction("my_range")
+    {
+        private static readonly Schema ResultSchema = new([new Field("n", Int64Type.Default, nullable: false)], []);
+
+        public bool Fail { get; init; }
+
+        public override IArrowArrayStream Call(IReadOnlyList<Proto.ScalarValueAndMetadata> arguments)
+        {
+            if (Fail)
+                throw new InvalidOperationException("Call failed");
+
+            var start = arguments[0].Value.Int64Value;
+            var count = (int)arguments[1].Value.Int64Value;
+            var values = new Int64Array.Builder().AppendRange(Enumerable.Range(0, count).Select(i => start + i)).Build();
+            return new SingleBatchStream(new RecordBatch(ResultSchema, [values], count));
+        }
+    }
+
+    private sealed class SingleBatchStream(RecordBatch batch) : IArrowArrayStream
+    {
+        private RecordBatch? _batch = batch;
+
+        public Schema Schema { get; } = batch.Schema;
+
+        public ValueTask<RecordBatch> ReadNextRecordBatchAsync(CancellationToken cancellationToken = default)
+        {
+            var next = _batch;
+            _batch = null;
+            return new ValueTask<RecordBatch>(next!);
+        }
+
+        public void Dispose()
+        {
+            _batch?.Dispose();
+        }
+    }
+}
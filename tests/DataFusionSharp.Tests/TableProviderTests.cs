@@ -0,0 +1,185 @@
+using Apache.Arrow;
+using Apache.Arrow.Ipc;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class TableProviderTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public TableProviderTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task RegisterTableProvider_QueryReadsRowsFromProvider()
+    {
+        // Arrange
+        var provider = new NumbersProvider();
+        _context.RegisterTableProvider("numbers", provider);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT id, name FROM numbers ORDER BY id");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var names = collected.Batches.SelectMany(b => b.Column("name").AsString()).ToList();
+        Assert.Equal(["one", "two", "three", "four", "five"], names);
+        var scan = Assert.Single(provider.Scans);
+        Assert.Empty(scan.Filters);
+        Assert.Null(scan.Limit);
+    }
+
+    [Fact]
+    public async Task RegisterTableProvider_WithInexactPushdown_PassesProjectionAndFilters()
+    {
+        // Arrange
+        var provider = new NumbersProvider { Pushdown = TableFilterPushdown.Inexact };
+        _context.RegisterTableProvider("numbers", provider);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT name FROM numbers WHERE id > 3 ORDER BY name");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var names = collected.Batches.SelectMany(b => b.Column("name").AsString()).ToList();
+        Assert.Equal(["five", "four"], names);
+        var scan = Assert.Single(provider.Scans);
+        Assert.NotNull(scan.Projection);
+        Assert.Equal([0, 1], scan.Projection.Order());
+        var filter = Assert.Single(scan.Filters);
+        Assert.Equal(Proto.LogicalExprNode.ExprTypeOneofCase.BinaryExpr, filter.ExprTypeCase);
+    }
+
+    [Fact]
+    public async Task RegisterTableProvider_WithLimit_PassesLimitToScan()
+    {
+        // Arrange
+        var provider = new NumbersProvider();
+        _context.RegisterTableProvider("numbers", provider);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT id FROM numbers LIMIT 2");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(2, collected.Batches.Sum(b => b.Length));
+        var scan = Assert.Single(provider.Scans);
+        Assert.Equal(2L, scan.Limit);
+        Assert.Equal([0], scan.Projection);
+    }
+
+    [Fact]
+    public async Task RegisterTableProvider_ScanThrows_QueryFails()
+    {
+        // Arrange
+        _context.RegisterTableProvider("numbers", new NumbersProvider { FailScan = true });
+
+        // Act & Assert
+        await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT * FROM numbers");
+            using var collected = await df.CollectAsync();
+        });
+    }
+
+    [Fact]
+    public void RegisterTableProvider_SchemaThrows_Throws()
+    {
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.RegisterTableProvider("broken", new BrokenSchemaProvider()));
+        Assert.Equal(DataFusionErrorCode.TableRegistrationFailed, ex.ErrorCode);
+        Assert.False(_context.TableExists("broken"));
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private sealed record ScanRequest(IReadOnlyList<int>? Projection, IReadOnlyList<Proto.LogicalExprNode> Filters, long? Limit);
+
+    private sealed class NumbersProvider : TableProvider
+    {
+        private static readonly long[] Ids = [1, 2, 3, 4, 5];
+        private static readonly string[] Names = ["one", "two", "three", "four", "five"];
+
+        private readonly List<ScanRequest> _scans = [];
+
+        public override Schema Schema { get; } = new(
+            [
+                new Field("id", Int64Type.Default, nullable: false),
+                new Field("name", StringType.Default, nullable: false)
+            ],
+            []);
+
+        public TableFilterPushdown Pushdown { get; init; } = TableFilterPushdown.Unsupported;
+
+        public bool FailScan { get; init; }
+
+        public IReadOnlyList<ScanRequest> Scans
+        {
+            get
+            {
+                lock (_scans)
+                    return _scans.ToList();
+            }
+        }
+
+        public override IArrowArrayStream Scan(IReadOnlyList<int>? projection, IReadOnlyList<Proto.LogicalExprNode> filters, long? limit)
+        {
+            lock (_scans)
+                _scans.Add(new ScanRequest(projection, filters, limit));
+
+            if (FailScan)
+                throw new InvalidOperationException("Scan failed");
+
+            var columns = projection ?? Enumerable.Range(0, Schema.FieldsList.Count).ToList();
+            var schema = new Schema(columns.Select(i => Schema.FieldsList[i]), []);
+            var arrays = columns.Select(i => i == 0
+                ? (IArrowArray)new Int64Array.Builder().AppendRange(Ids).Build()
+                : new StringArray.Builder().AppendRange(Names).Build());
+            return new BatchStream(schema, [new RecordBatch(schema, arrays, Ids.Length)]);
+        }
+
+        public override IReadOnlyList<TableFilterPushdown> SupportsFiltersPushdown(IReadOnlyList<Proto.LogicalExprNode> filters)
+        {
+            return Enumerable.Repeat(Pushdown, filters.Count).ToList();
+        }
+    }
+
+    private sealed class BrokenSchemaProvider : TableProvider
+    {
+#pragma warning disable CA1065 // The failure is what the test checks
+        public override Schema Schema => throw new InvalidOperationException("No schema");
+#pragma warning restore CA1065
+
+        public override IArrowArrayStream Scan(IReadOnlyList<int>? projection, IReadOnlyList<Proto.LogicalExprNode> filters, long? limit)
+        {
+            throw new NotSupportedException();
+        }
+    }
+
+    private sealed class BatchStream(Schema schema, IReadOnlyList<RecordBatch> batches) : IArrowArrayStream
+    {
+        private int _index;
+
+        public Schema Schema => schema;
+
+        public ValueTask<RecordBatch> ReadNextRecordBatchAsync(CancellationToken cancellationToken = default)
+        {
+            return new ValueTask<RecordBatch>(_index < batches.Count ? batches[_index++] : null!);
+        }
+
+        public void Dispose()
+        {
+            for (; _index < batches.Count; _index++)
+                batches[_index].Dispose();
+        }
+    }
+}
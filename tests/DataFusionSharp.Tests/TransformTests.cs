@@ -0,0 +1,116 @@
+namespace DataFusionSharp.Tests;
+
+public sealed class TransformTests : IDisposable
+{
+    private const string Query = "SELECT * FROM (VALUES (1, 'x'), (2, 'y'), (3, 'x'), (3, 'x')) t(a, b)";
+
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public TransformTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task Filter_KeepsMatchingRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        using var filtered = df.Filter(Binary(Col("a"), "Gt", Lit(1)));
+        using var collected = await filtered.CollectAsync();
+
+        // Assert
+        var values = collected.Batches.SelectMany(b => b.Column("a").AsInt64()).ToList();
+        Assert.Equal([2L, 3L, 3L], values);
+    }
+
+    [Fact]
+    public async Task Select_WithColumn_Sort_Limit_ChainsTransformations()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        using var withColumn = df.WithColumn("c", Binary(Col("a"), "Plus", Lit(10)));
+        using var selected = withColumn.Select([Col("c")]);
+        using var sorted = selected.Sort([new Proto.SortExprNode { Expr = Col("c"), Asc = false }]);
+        using var limited = sorted.Limit(fetch: 2, skip: 1);
+        using var collected = await limited.CollectAsync();
+
+        // Assert
+        Assert.Equal(["c"], collected.Schema.FieldsList.Select(f => f.Name));
+        var values = collected.Batches.SelectMany(b => b.Column("c").AsInt64()).ToList();
+        Assert.Equal([13L, 12L], values);
+    }
+
+    [Fact]
+    public async Task Aggregate_GroupsRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+        var sum = new Proto.LogicalExprNode
+        {
+            AggregateUdfExpr = new Proto.AggregateUDFExprNode { FunName = "sum", Args = { Col("a") } }
+        };
+
+        // Act
+        using var aggregated = df.Aggregate([Col("b")], [Alias(sum, "total")]);
+        using var sorted = aggregated.Sort([new Proto.SortExprNode { Expr = Col("b"), Asc = true }]);
+        using var collected = await sorted.CollectAsync();
+
+        // Assert
+        var keys = collected.Batches.SelectMany(b => b.Column("b").AsString()).ToList();
+        var totals = collected.Batches.SelectMany(b => b.Column("total").AsInt64()).ToList();
+        Assert.Equal(["x", "y"], keys);
+        Assert.Equal([7L, 2L], totals);
+    }
+
+    [Fact]
+    public async Task WithColumnRenamed_DropColumns_Distinct_ReshapeRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act
+        using var renamed = df.WithColumnRenamed("b", "category");
+        using var dropped = renamed.DropColumns(["a"]);
+        using var distinct = dropped.Distinct();
+        var count = await distinct.CountAsync();
+        using var collected = await distinct.CollectAsync();
+
+        // Assert
+        Assert.Equal(["category"], collected.Schema.FieldsList.Select(f => f.Name));
+        Assert.Equal(2UL, count);
+    }
+
+    [Fact]
+    public async Task Filter_UnknownColumn_Throws()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(Query);
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => df.Filter(Binary(Col("missing"), "Gt", Lit(1))));
+        Assert.Contains("missing", ex.Message, StringComparison.Ordinal);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static Proto.LogicalExprNode Col(string name) => new() { Column = new Proto.Column { Name = name } };
+
+    private static Proto.LogicalExprNode Lit(long value) => new() { Literal = new Proto.ScalarValue { Int64Value = value } };
+
+    private static Proto.LogicalExprNode Binary(Proto.LogicalExprNode left, string op, Proto.LogicalExprNode right) =>
+        new() { BinaryExpr = new Proto.BinaryExprNode { Operands = { left, right }, Op = op } };
+
+    private static Proto.LogicalExprNode Alias(Proto.LogicalExprNode expr, string alias) =>
+        new() { Alias = new Proto.AliasNode { Expr = expr, Alias = alias } };
+}
@@ -0,0 +1,85 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class ViewTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public ViewTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+        RegisterNumbers(_context);
+    }
+
+    [Fact]
+    public async Task RegisterView_ParameterizedDataFrame_IsQueryableBySql()
+    {
+        // Arrange
+        using (var df = await _context.SqlAsync("SELECT id FROM numbers WHERE id > $min"))
+        {
+            df.WithParameters([("min", 2L)]);
+
+            // Act
+            _context.RegisterView("big_numbers", df);
+        }
+
+        using var result = await _context.SqlAsync("SELECT id FROM big_numbers ORDER BY id");
+        using var collected = await result.CollectAsync();
+
+        // Assert
+        var ids = collected.Batches.SelectMany(b => b.Column("id").AsInt64()).ToList();
+        Assert.Equal([3L, 4L, 5L], ids);
+    }
+
+    [Fact]
+    public async Task RegisterView_UnknownCatalog_Throws()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT id FROM numbers");
+
+        // Act & Assert
+        var ex = Assert.Throws<DataFusionException>(() => _context.RegisterView("missing.public.view", df));
+        Assert.Equal(DataFusionErrorCode.TableRegistrationFailed, ex.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CacheAsync_RegisteredAsView_DoesNotReevaluateQuery()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT id, random() AS r FROM numbers");
+        using var cached = await df.CacheAsync();
+        _context.RegisterView("cached_numbers", cached);
+
+        // Act
+        var first = await CollectRandomAsync("SELECT r FROM cached_numbers ORDER BY id");
+        var second = await CollectRandomAsync("SELECT r FROM cached_numbers ORDER BY id");
+
+        // Assert
+        Assert.Equal(5, first.Count);
+        Assert.Equal(first, second);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private async Task<List<double?>> CollectRandomAsync(string sql)
+    {
+        using var df = await _context.SqlAsync(sql);
+        using var collected = await df.CollectAsync();
+        return collected.Batches.SelectMany(b => b.Column("r").AsDouble()).ToList();
+    }
+
+    private static void RegisterNumbers(SessionContext context)
+    {
+        var schema = new Schema([new Field("id", Int64Type.Default, nullable: false)], []);
+        using var batch = new RecordBatch(schema, [new Int64Array.Builder().AppendRange([1, 2, 3, 4, 5]).Build()], 5);
+        context.RegisterBatches("numbers", schema, [batch]);
+    }
+}
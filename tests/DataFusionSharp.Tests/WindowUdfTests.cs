@@ -0,0 +1,110 @@
+using Apache.Arrow;
+using Apache.Arrow.Types;
+using DataFusionSharp.Functions;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class WindowUdfTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public WindowUdfTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task RegisterWindowUdf_EvaluatesEachPartitionInOrder()
+    {
+        // Arrange
+        _context.RegisterWindowUdf(new RunningTotalUdf());
+
+        // Act
+        using var df = await _context.SqlAsync(
+            """
+            SELECT k, i, running_total(v) OVER (PARTITION BY k ORDER BY i) AS total
+            FROM (VALUES ('a', 1, 10), ('a', 2, 20), ('b', 1, 5), ('a', 3, 30)) t(k, i, v)
+            ORDER BY k, i
+            """);
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var totals = collected.Batches.SelectMany(b => b.Column("total").AsInt64()).ToList();
+        Assert.Equal([10L, 30L, 60L, 5L], totals);
+    }
+
+    [Fact]
+    public async Task RegisterWindowUdf_WithRank_ReceivesPeerRanges()
+    {
+        // Arrange
+        _context.RegisterWindowUdf(new DenseRankUdf());
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT v, my_dense_rank() OVER (ORDER BY v) AS r FROM (VALUES (10), (20), (20), (30)) t(v) ORDER BY v");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var ranks = collected.Batches.SelectMany(b => b.Column("r").AsInt64()).ToList();
+        Assert.Equal([1L, 2L, 2L, 3L], ranks);
+    }
+
+    [Fact]
+    public async Task RegisterWindowUdf_EvaluateThrows_QueryFails()
+    {
+        // Arrange
+        _context.RegisterWindowUdf(new RunningTotalUdf { Fail = true });
+
+        // Act & Assert
+        var ex = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT running_total(v) OVER (ORDER BY v) FROM (VALUES (1)) t(v)");
+            using var collected = await df.CollectAsync();
+        });
+        Assert.Contains("running_total", ex.Message, StringComparison.Ordinal);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private sealed class RunningTotalUdf() : WindowUdf("running_total", [Int64Type.Default], Int64Type.Default, Volatility.Immutable)
+    {
+        public bool Fail { get; init; }
+
+        public override IArrowArray Evaluate(IReadOnlyList<IArrowArray> arguments, int rowCount)
+        {
+            if (Fail)
+                throw new InvalidOperationException("Evaluate failed");
+
+            var total = 0L;
+            var builder = new Int64Array.Builder();
+            foreach (var value in arguments[0].AsInt64())
+            {
+                total += value ?? 0;
+                builder.Append(total);
+            }
+            return builder.Build();
+        }
+    }
+
+    private sealed class DenseRankUdf() : WindowUdf("my_dense_rank", [], Int64Type.Default, Volatility.Immutable)
+    {
+        public override bool IncludeRank => true;
+
+        public override IArrowArray EvaluateWithRank(int rowCount, IReadOnlyList<Range> ranks)
+        {
+            var builder = new Int64Array.Builder();
+            for (var rank = 0; rank < ranks.Count; rank++)
+            {
+                var (_, peers) = ranks[rank].GetOffsetAndLength(rowCount);
+                for (var i = 0; i < peers; i++)
+                    builder.Append(rank + 1);
+            }
+            return builder.Build();
+        }
+    }
+}